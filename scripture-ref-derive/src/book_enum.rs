@@ -1,11 +1,16 @@
-use quote::{format_ident, quote};
+use quote::quote;
 use syn::{Data, DeriveInput, Error, Ident};
 
-use crate::book_variant::BookVariantData;
+use crate::{
+    book_variant::BookVariantData,
+    language::{language_feature, language_variant, normalize_name, ordinal, strip_ordinal},
+};
 
 pub struct BookEnumData {
     pub name: Ident,
     pub variants: Vec<BookVariantData>,
+    /// The codes of the languages book names are declared in, in order of first appearance.
+    pub languages: Vec<String>,
 }
 
 impl BookEnumData {
@@ -23,9 +28,58 @@ impl BookEnumData {
             .map(BookVariantData::from_variant)
             .collect::<syn::Result<Vec<_>>>()?;
 
+        // book IDs are permanent, so they are never inferred and can never be shared
+        for (i, (variant, data)) in data_enum.variants.iter().zip(&variants).enumerate() {
+            if let Some(other) = variants[..i].iter().find(|other| other.id == data.id) {
+                return Err(Error::new_spanned(
+                    &variant.discriminant.as_ref().expect("IDs are validated").1,
+                    format!("book ID {} is already used by {}", data.id, other.name),
+                ));
+            }
+        }
+
+        let mut languages: Vec<String> = Vec::new();
+        for (code, _) in variants.iter().flat_map(|v| &v.names) {
+            if !languages.contains(code) {
+                languages.push(code.clone());
+            }
+        }
+
+        for (variant, data) in data_enum.variants.iter().zip(&variants) {
+            for code in &languages {
+                if data.name(code).is_none() {
+                    return Err(Error::new_spanned(
+                        variant,
+                        format!("missing a name in '{code}'"),
+                    ));
+                }
+            }
+        }
+
+        // every enabled language is searched when parsing a name, so a name may only be shared
+        // between languages if it names the same book
+        let mut seen: Vec<(String, &Ident)> = Vec::new();
+        for code in &languages {
+            for (variant, data) in data_enum.variants.iter().zip(&variants) {
+                for name in data.all_names(code).map(normalize_name) {
+                    match seen.iter().find(|(other, _)| *other == name) {
+                        Some((_, book)) if *book != &data.name => {
+                            return Err(Error::new_spanned(
+                                variant,
+                                format!("'{name}' already names {book} in '{code}'"),
+                            ));
+                        }
+                        Some(_) => {}
+                        None => seen.push((name, &data.name)),
+                    }
+                }
+            }
+        }
+
         Ok(Self {
             name: input.ident.clone(),
             variants,
+            languages,
         })
     }
 }
@@ -35,11 +89,22 @@ impl BookEnumData {
         let book_impl = self.generate_book_impl();
         let book_series_enum = self.generate_book_series_enum();
         let book_series_impl = self.generate_book_series_impl();
+        // names are optional, but without them books cannot be displayed or parsed
+        let (language_enum, book_names_impl) = if self.languages.is_empty() {
+            Default::default()
+        } else {
+            (
+                self.generate_language_enum(),
+                self.generate_book_names_impl(),
+            )
+        };
 
         quote! {
             #book_impl
             #book_series_enum
             #book_series_impl
+            #language_enum
+            #book_names_impl
         }
     }
 
@@ -48,9 +113,89 @@ impl BookEnumData {
         let chapters_arms = self.generate_chapters_arms();
         let verses_arms = self.generate_verses_arms();
         let canonical_name_arms = self.generate_canonical_name_arms();
+        let variants: Vec<&Ident> = self.variants.iter().map(|v| &v.name).collect();
+        let ids: Vec<u8> = self.variants.iter().map(|v| v.id).collect();
+        let testaments: Vec<&Ident> = self
+            .variants
+            .iter()
+            .map(|v| v.testament.as_ref().expect("testaments are validated"))
+            .collect();
+        let count = self.variants.len();
+        let in_testament = |testament: &str| -> Vec<&Ident> {
+            self.variants
+                .iter()
+                .filter(|v| v.testament.as_ref().is_some_and(|t| t == testament))
+                .map(|v| &v.name)
+                .collect()
+        };
+        let old_testament = in_testament("Old");
+        let new_testament = in_testament("New");
+        let deuterocanon = in_testament("Deuterocanon");
+        let bible: Vec<&Ident> = self
+            .variants
+            .iter()
+            .filter(|v| v.testament.as_ref().is_some_and(|t| t != "Deuterocanon"))
+            .map(|v| &v.name)
+            .collect();
+        let (old_count, new_count, deuterocanon_count, bible_count) = (
+            old_testament.len(),
+            new_testament.len(),
+            deuterocanon.len(),
+            bible.len(),
+        );
 
         quote! {
+            /// The part of the Bible a book belongs to.
+            #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+            pub enum Testament {
+                Old,
+                New,
+                /// Books found in the Old Testament of some canons, but not of the Hebrew Bible.
+                Deuterocanon,
+            }
+
             impl #enum_name {
+                /// The number of books.
+                pub const COUNT: usize = #count;
+
+                /// Every book, in the order they are declared.
+                pub const ALL: [Self; #count] = [#(#enum_name::#variants),*];
+
+                /// The books of the Old Testament, in the order they are declared.
+                pub const OLD_TESTAMENT: [Self; #old_count] = [#(#enum_name::#old_testament),*];
+
+                /// The books of the New Testament, in the order they are declared.
+                pub const NEW_TESTAMENT: [Self; #new_count] = [#(#enum_name::#new_testament),*];
+
+                /// The deuterocanonical books, in the order they are declared.
+                pub const DEUTEROCANON: [Self; #deuterocanon_count] =
+                    [#(#enum_name::#deuterocanon),*];
+
+                /// The books of the Old and New Testaments, in the order they are declared.
+                pub const BIBLE: [Self; #bible_count] = [#(#enum_name::#bible),*];
+
+                /// Returns the permanent ID of the book.
+                pub const fn id(&self) -> u8 {
+                    match self {
+                        #(#enum_name::#variants => #ids,)*
+                    }
+                }
+
+                /// Returns the book with the given permanent ID, if any.
+                pub const fn from_id(id: u8) -> Option<Self> {
+                    match id {
+                        #(#ids => Some(#enum_name::#variants),)*
+                        _ => None,
+                    }
+                }
+
+                /// Returns the testament the book belongs to.
+                pub const fn testament(&self) -> Testament {
+                    match self {
+                        #(#enum_name::#variants => Testament::#testaments,)*
+                    }
+                }
+
                 /// Returns the number of chapters in the book.
                 pub fn chapter_count(&self) -> u8 {
                     match self {
@@ -146,24 +291,27 @@ impl BookEnumData {
             .collect()
     }
 
-    // books without a series default to the enum name
-    // books with a series default to the series name
+    /// Returns the series of each book, in the order the series first appear.
+    ///
+    /// Books without a series default to the enum name, while books with a series default to
+    /// the series name.
+    fn series(&self) -> Vec<Ident> {
+        let mut series: Vec<Ident> = Vec::new();
+        for variant in &self.variants {
+            let name = variant.series_name();
+            if !series.contains(&name) {
+                series.push(name);
+            }
+        }
+        series
+    }
+
     fn generate_book_series_enum(&self) -> proc_macro2::TokenStream {
-        let series: std::collections::HashSet<String> = self
-            .variants
-            .iter()
-            .map(|v| {
-                let variant_name = v.name.to_string();
-                v.series.as_ref().unwrap_or(&variant_name).to_owned()
-            })
-            .collect();
-        let series_arms: Vec<syn::Ident> = series
-            .iter()
-            .map(|series| syn::Ident::new(series, proc_macro2::Span::call_site()))
-            .collect();
+        let series_arms = self.series();
         quote! {
+            /// The name books share apart from their number, e.g. "Kings" for 1 and 2 Kings.
             #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-            pub(crate) enum BookSeries {
+            pub enum BookSeries {
                 #(#series_arms,)*
             }
         }
@@ -172,17 +320,17 @@ impl BookEnumData {
     fn generate_book_series_impl(&self) -> proc_macro2::TokenStream {
         let enum_name = &self.name;
         let book_series = self.variants.iter().map(|v| {
-            let variant_name = format_ident!("{}", v.name);
-            let series_name = &v
-                .series
-                .as_ref()
-                .map(|s| format_ident!("{}", s))
-                .unwrap_or(variant_name.clone());
+            let variant_name = &v.name;
+            let series_name = v.series_name();
             quote! {
                 #enum_name::#variant_name => BookSeries::#series_name,
             }
         });
-        let book_series_display = "REPLCE_ME_WITH_DISPLAY_IMPL".to_string();
+        let series_names_impl = if self.languages.is_empty() {
+            self.generate_series_identifier_names()
+        } else {
+            self.generate_series_language_names()
+        };
         quote! {
             impl BookSeries {
                 pub fn from_book(book: &Book) -> Self {
@@ -192,9 +340,25 @@ impl BookEnumData {
                 }
             }
 
+            #series_names_impl
+        }
+    }
+
+    /// Names each series after its identifier, for enums that do not declare their names.
+    fn generate_series_identifier_names(&self) -> proc_macro2::TokenStream {
+        let series = self.series();
+        let display_names: Vec<String> = series
+            .iter()
+            .map(|s| display_name(&s.to_string()))
+            .collect();
+        let lowercase_names = display_names.iter().map(|name| name.to_lowercase());
+        quote! {
             impl std::fmt::Display for BookSeries {
                 fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    write!(f, "{}", #book_series_display)
+                    let name = match self {
+                        #(BookSeries::#series => #display_names,)*
+                    };
+                    write!(f, "{}", name)
                 }
             }
 
@@ -202,15 +366,324 @@ impl BookEnumData {
                 type Err = String;
 
                 fn from_str(s: &str) -> Result<Self, Self::Err> {
-                    match s {
-                        // TODO: add cases for all books (how to handle multi-word books?)
+                    match s.to_lowercase().as_str() {
+                        #(#lowercase_names => Ok(BookSeries::#series),)*
                         _ => Err(format!("not a valid book series: {}", s)),
                     }
                 }
             }
+        }
+    }
+
+    /// Names each series after the names of its books in each language.
+    fn generate_series_language_names(&self) -> proc_macro2::TokenStream {
+        let series = self.series();
+        let name_arms = self.languages.iter().map(|code| {
+            let feature = language_feature(code);
+            let language = language_variant(code);
+            // a series is named after the first of its books, without the book's number
+            let names = series.iter().map(|s| {
+                self.variants
+                    .iter()
+                    .find(|v| v.series_name() == *s)
+                    .and_then(|v| v.name(code))
+                    .map(strip_ordinal)
+                    .unwrap_or_default()
+            });
+            quote! {
+                #[cfg(feature = #feature)]
+                Language::#language => match self {
+                    #(BookSeries::#series => #names,)*
+                },
+            }
+        });
+        quote! {
+            impl BookSeries {
+                /// Returns the name of the series in the given language, e.g. "Kings".
+                pub fn name(&self, language: Language) -> &'static str {
+                    match language {
+                        #(#name_arms)*
+                    }
+                }
+            }
+
+            impl std::fmt::Display for BookSeries {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", self.name(Language::ENABLED[0]))
+                }
+            }
+
+            impl std::str::FromStr for BookSeries {
+                type Err = String;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let name = Language::normalize(s);
+                    Language::ENABLED
+                        .iter()
+                        .flat_map(|language| language.series_names())
+                        .find(|(alias, _)| *alias == name)
+                        .map(|(_, series)| *series)
+                        .ok_or_else(|| format!("not a valid book series: {}", s))
+                }
+            }
+        }
+    }
+
+    fn generate_language_enum(&self) -> proc_macro2::TokenStream {
+        let enum_name = &self.name;
+        let features: Vec<String> = self.languages.iter().map(|c| language_feature(c)).collect();
+        let languages: Vec<Ident> = self.languages.iter().map(|c| language_variant(c)).collect();
+        let missing_language = format!(
+            "at least one language feature must be enabled (e.g., {})",
+            features.join(", ")
+        );
+
+        let book_names = self.languages.iter().map(|code| {
+            let mut names: Vec<(String, &Ident)> = Vec::new();
+            for v in &self.variants {
+                for name in v.all_names(code).map(normalize_name) {
+                    if !names.iter().any(|(other, _)| *other == name) {
+                        names.push((name, &v.name));
+                    }
+                }
+            }
+            let (names, books): (Vec<_>, Vec<_>) = names.into_iter().unzip();
+            quote! { &[#((#names, #enum_name::#books)),*] }
+        });
+
+        let series_names = self.languages.iter().map(|code| {
+            let (names, series): (Vec<_>, Vec<_>) = self.series_names(code).into_iter().unzip();
+            quote! { &[#((#names, BookSeries::#series)),*] }
+        });
+
+        let series_books = self.languages.iter().map(|code| {
+            let series_names = self.series_names(code);
+            let own_series: Vec<Ident> = self.variants.iter().map(|v| v.series_name()).collect();
+            let mut books: Vec<(Option<u8>, &Ident, &Ident)> = Vec::new();
+            for (v, own_series) in self.variants.iter().zip(&own_series) {
+                for name in v.all_names(code) {
+                    // books are also found by the series that claimed their name, so 3 Esdras
+                    // is still found in Spanish even though Ezra claimed "Esdras"
+                    let unnumbered = normalize_name(strip_ordinal(name));
+                    let (_, claimed_by) = series_names
+                        .iter()
+                        .find(|(other, _)| *other == unnumbered)
+                        .expect("every name claims a series");
+                    let number = ordinal(name);
+                    for series in [own_series, claimed_by] {
+                        if !books.iter().any(|(n, s, _)| *n == number && *s == series) {
+                            books.push((number, series, &v.name));
+                        }
+                    }
+                }
+            }
+            let numbers = books.iter().map(|(number, _, _)| match number {
+                Some(n) => quote! { Some(#n) },
+                None => quote! { None },
+            });
+            let series = books.iter().map(|(_, series, _)| series);
+            let books = books.iter().map(|(_, _, book)| book);
+            quote! { &[#((#numbers, BookSeries::#series, #enum_name::#books)),*] }
+        });
+
+        quote! {
+            /// A language book names are available in, one for each enabled `lang-*` feature.
+            #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+            pub enum Language {
+                #(
+                    #[cfg(feature = #features)]
+                    #languages,
+                )*
+            }
+
+            #[cfg(not(any(#(feature = #features),*)))]
+            compile_error!(#missing_language);
+
+            impl Language {
+                /// The enabled languages, in order of preference.
+                pub const ENABLED: &'static [Language] = &[
+                    #(
+                        #[cfg(feature = #features)]
+                        Language::#languages,
+                    )*
+                ];
+
+                /// Normalizes a book name for lookup, ignoring case, repeated whitespace and a
+                /// trailing period.
+                fn normalize(name: &str) -> String {
+                    name.trim()
+                        .trim_end_matches('.')
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                        .to_lowercase()
+                }
+
+                /// The normalized names and aliases of every book in the language.
+                fn book_names(&self) -> &'static [(&'static str, #enum_name)] {
+                    match *self {
+                        #(
+                            #[cfg(feature = #features)]
+                            Language::#languages => #book_names,
+                        )*
+                    }
+                }
+
+                /// The normalized names and aliases of every series in the language, which are
+                /// the names of their books without a number.
+                fn series_names(&self) -> &'static [(&'static str, BookSeries)] {
+                    match *self {
+                        #(
+                            #[cfg(feature = #features)]
+                            Language::#languages => #series_names,
+                        )*
+                    }
+                }
+
+                /// The books named by the number in front of their names and their series in
+                /// the language, e.g. 1 and Kings for 1 Kings.
+                fn series_books(&self) -> &'static [(Option<u8>, BookSeries, #enum_name)] {
+                    match *self {
+                        #(
+                            #[cfg(feature = #features)]
+                            Language::#languages => #series_books,
+                        )*
+                    }
+                }
+            }
+        }
+    }
 
+    /// The normalized names and aliases of every series in the language, without the numbers
+    /// in front of the names of their books.
+    fn series_names(&self, code: &str) -> Vec<(String, Ident)> {
+        let mut names: Vec<(String, Ident)> = Vec::new();
+        for v in &self.variants {
+            for name in v.all_names(code).map(|n| normalize_name(strip_ordinal(n))) {
+                // the first series to claim a name keeps it, e.g. Ezra keeps "Esdras" in
+                // Spanish over 3 Esdras
+                if !names.iter().any(|(other, _)| *other == name) {
+                    names.push((name, v.series_name()));
+                }
+            }
         }
+        names
     }
+
+    fn generate_book_names_impl(&self) -> proc_macro2::TokenStream {
+        let enum_name = &self.name;
+        let variants: Vec<&Ident> = self.variants.iter().map(|v| &v.name).collect();
+        let name_arms = self.languages.iter().map(|code| {
+            let feature = language_feature(code);
+            let language = language_variant(code);
+            let names = self
+                .variants
+                .iter()
+                .map(|v| v.name(code).unwrap_or_default());
+            quote! {
+                #[cfg(feature = #feature)]
+                Language::#language => match self {
+                    #(#enum_name::#variants => #names,)*
+                },
+            }
+        });
+        let alias_arms = self.languages.iter().map(|code| {
+            let feature = language_feature(code);
+            let language = language_variant(code);
+            let aliases = self.variants.iter().map(|v| {
+                let aliases = v.aliases(code);
+                quote! { &[#(#aliases),*] }
+            });
+            quote! {
+                #[cfg(feature = #feature)]
+                Language::#language => match self {
+                    #(#enum_name::#variants => #aliases,)*
+                },
+            }
+        });
+
+        quote! {
+            impl #enum_name {
+                /// Returns the name of the book in the given language, e.g. "1 Kings".
+                pub fn name(&self, language: Language) -> &'static str {
+                    match language {
+                        #(#name_arms)*
+                    }
+                }
+
+                /// Returns the abbreviations and alternative names of the book in the given
+                /// language, e.g. "1 Kgs".
+                pub fn aliases(&self, language: Language) -> &'static [&'static str] {
+                    match language {
+                        #(#alias_arms)*
+                    }
+                }
+
+                /// Finds a book by the number in front of its name and its series in any enabled
+                /// language, e.g. `FirstKings` for 1 and `BookSeries::Kings`.
+                pub fn from_series(number: Option<u8>, series: BookSeries) -> Option<Self> {
+                    Language::ENABLED
+                        .iter()
+                        .flat_map(|language| language.series_books())
+                        .find(|(n, s, _)| *n == number && *s == series)
+                        .map(|(_, _, book)| *book)
+                }
+            }
+
+            impl std::fmt::Display for #enum_name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", self.name(Language::ENABLED[0]))
+                }
+            }
+
+            impl TryFrom<&str> for #enum_name {
+                type Error = String;
+
+                /// Finds a book by its name or alias in any enabled language, ignoring case.
+                fn try_from(value: &str) -> Result<Self, Self::Error> {
+                    let name = Language::normalize(value);
+                    Language::ENABLED
+                        .iter()
+                        .flat_map(|language| language.book_names())
+                        .find(|(alias, _)| *alias == name)
+                        .map(|(_, book)| *book)
+                        .ok_or_else(|| format!("not a valid book: {}", value))
+                }
+            }
+        }
+    }
+}
+
+/// Splits a series identifier into the words of its name, e.g. `SongOfSongs` into
+/// "Song of Songs" and `Psalm151` into "Psalm 151".
+fn display_name(ident: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    for c in ident.chars() {
+        let starts_word = match previous {
+            None => true,
+            Some(p) => {
+                (c.is_uppercase() && !p.is_uppercase())
+                    || (c.is_ascii_digit() != p.is_ascii_digit())
+            }
+        };
+        if starts_word {
+            words.push(String::new());
+        }
+        if let Some(word) = words.last_mut() {
+            word.push(c);
+        }
+        previous = Some(c);
+    }
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, word)| match word.as_str() {
+            "Of" | "And" | "The" if i > 0 => word.to_lowercase(),
+            _ => word.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 #[cfg(test)]
@@ -218,34 +691,50 @@ mod tests {
     use super::*;
     use quote::quote;
 
+    fn parse(tokens: proc_macro2::TokenStream) -> syn::Result<BookEnumData> {
+        BookEnumData::from_derive_input(&syn::parse2(tokens).unwrap())
+    }
+
+    fn error(tokens: proc_macro2::TokenStream) -> String {
+        match parse(tokens) {
+            Ok(_) => panic!("expected the derive input to be rejected"),
+            Err(err) => err.to_string(),
+        }
+    }
+
     #[test]
     fn test_from_derive_input() {
         let tokens = quote! {
             enum Book {
                 #[chapters = "3"]
                 #[verses = "31, 25, 24"]
+                #[testament = "old"]
                 Alpha = 1,
                 #[chapters = "2"]
-                #[canonical_name = "The Book of Beta"]
-                Beta,
+                #[verses = "22, 17"]
+                #[testament = "old"]
+                #[series = "Beta"]
+                FirstBeta = 2,
             }
         };
 
-        let data = BookEnumData::from_derive_input(&syn::parse2(tokens).unwrap()).unwrap();
+        let data = parse(tokens).unwrap();
 
         assert_eq!(data.name, "Book");
         assert_eq!(data.variants.len(), 2);
 
         let first_variant = &data.variants[0];
         assert_eq!(first_variant.name, "Alpha");
+        assert_eq!(first_variant.id, 1);
         assert_eq!(first_variant.num_chapters, Some(3));
         assert_eq!(first_variant.max_verses_per_chapter, vec![31, 25, 24]);
 
         let second_variant = &data.variants[1];
-        assert_eq!(second_variant.name, "Beta");
+        assert_eq!(second_variant.name, "FirstBeta");
+        assert_eq!(second_variant.id, 2);
         assert_eq!(second_variant.num_chapters, Some(2));
-        assert!(second_variant.max_verses_per_chapter.is_empty());
-        assert_eq!(second_variant.series, Some("The Book of Beta".to_string()));
+        assert_eq!(second_variant.max_verses_per_chapter, vec![22, 17]);
+        assert_eq!(second_variant.series, Some("Beta".to_string()));
     }
 
     #[test]
@@ -258,4 +747,277 @@ mod tests {
         let result = BookEnumData::from_derive_input(&input);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_series_are_listed_once_in_order() {
+        let tokens = quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[series = "Kings"]
+                FirstKings = 1,
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[series = "Kings"]
+                SecondKings = 2,
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                Psalms = 3,
+            }
+        };
+
+        let data = parse(tokens).unwrap();
+        let series: Vec<String> = data.series().iter().map(|s| s.to_string()).collect();
+        assert_eq!(series, vec!["Kings", "Psalms"]);
+    }
+
+    #[test]
+    fn test_display_name() {
+        assert_eq!(display_name("Genesis"), "Genesis");
+        assert_eq!(display_name("SongOfSongs"), "Song of Songs");
+        assert_eq!(display_name("PrayerOfManasseh"), "Prayer of Manasseh");
+        assert_eq!(display_name("Psalm151"), "Psalm 151");
+    }
+
+    #[test]
+    fn test_names_and_aliases() {
+        let tokens = quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[names(en = "1 Kings", es = "1 Reyes")]
+                #[aliases(en = "1 Kgs, 1 Ki")]
+                FirstKings = 11,
+            }
+        };
+
+        let data = parse(tokens).unwrap();
+        assert_eq!(data.languages, vec!["en", "es"]);
+
+        let variant = &data.variants[0];
+        assert_eq!(variant.name("es"), Some("1 Reyes"));
+        let names: Vec<&str> = variant.all_names("en").collect();
+        assert_eq!(names, vec!["1 Kings", "1 Kgs", "1 Ki"]);
+    }
+
+    #[test]
+    fn test_rejects_missing_names() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[names(en = "Genesis", es = "Génesis")]
+                Genesis = 1,
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[names(en = "Exodus")]
+                Exodus = 2,
+            }
+        });
+        assert_eq!(message, "missing a name in 'es'");
+    }
+
+    #[test]
+    fn test_rejects_ambiguous_aliases() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[names(en = "Judges")]
+                #[aliases(en = "Jud")]
+                Judges = 7,
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[names(en = "Jude")]
+                #[aliases(en = "Jud")]
+                Jude = 65,
+            }
+        });
+        assert_eq!(message, "'jud' already names Judges in 'en'");
+    }
+
+    #[test]
+    fn test_rejects_ambiguous_aliases_across_languages() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[names(en = "Micah", es = "Miqueas")]
+                #[aliases(en = "Mc")]
+                Micah = 33,
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[names(en = "Mark", es = "Marcos")]
+                #[aliases(es = "Mc")]
+                Mark = 41,
+            }
+        });
+        assert_eq!(message, "'mc' already names Micah in 'es'");
+    }
+
+    #[test]
+    fn test_rejects_unsupported_languages() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[names(xx = "Genesis")]
+                Genesis = 1,
+            }
+        });
+        assert_eq!(message, "unsupported language 'xx'");
+    }
+
+    #[test]
+    fn test_rejects_missing_ids() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                Genesis = 1,
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                Exodus,
+            }
+        });
+        assert!(message.contains("explicit, permanent ID"), "{message}");
+    }
+
+    #[test]
+    fn test_rejects_duplicate_ids() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                Genesis = 1,
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                Exodus = 1,
+            }
+        });
+        assert_eq!(message, "book ID 1 is already used by Genesis");
+    }
+
+    #[test]
+    fn test_rejects_zero_verses() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "2"]
+                #[verses = "10, 0"]
+                #[testament = "old"]
+                Genesis = 1,
+            }
+        });
+        assert!(message.starts_with("chapter 2 has 0 verses"), "{message}");
+    }
+
+    #[test]
+    fn test_rejects_too_many_chapters_or_verses() {
+        let too_many_chapters = error(quote! {
+            enum Book {
+                #[chapters = "151"]
+                #[verses = "10"]
+                #[testament = "old"]
+                Psalms = 19,
+            }
+        });
+        assert_eq!(
+            too_many_chapters,
+            "a book must have between 1 and 150 chapters"
+        );
+
+        let too_many_verses = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "177"]
+                #[testament = "old"]
+                Psalms = 19,
+            }
+        });
+        assert!(too_many_verses.starts_with("chapter 1 has 177 verses"));
+    }
+
+    #[test]
+    fn test_rejects_mismatched_chapters_and_verses() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "2"]
+                #[verses = "10"]
+                #[testament = "old"]
+                Genesis = 1,
+            }
+        });
+        assert_eq!(
+            message,
+            "Number of chapters does not match number of verses"
+        );
+    }
+
+    #[test]
+    fn test_rejects_unknown_attributes() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                #[canonical_name = "The Book of Beta"]
+                Beta = 2,
+            }
+        });
+        assert_eq!(message, "unknown attribute for a book");
+    }
+
+    #[test]
+    fn test_rejects_missing_testaments() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                Genesis = 1,
+            }
+        });
+        assert_eq!(message, "a book must declare its #[testament]");
+    }
+
+    #[test]
+    fn test_rejects_unknown_testaments() {
+        let message = error(quote! {
+            enum Book {
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "apocrypha"]
+                Genesis = 1,
+            }
+        });
+        assert_eq!(message, "expected \"old\", \"new\" or \"deuterocanon\"");
+    }
+
+    #[test]
+    fn test_allows_doc_comments() {
+        let tokens = quote! {
+            enum Book {
+                /// The first book.
+                #[chapters = "1"]
+                #[verses = "10"]
+                #[testament = "old"]
+                Genesis = 1,
+            }
+        };
+        assert!(parse(tokens).is_ok());
+    }
 }
@@ -1,63 +1,224 @@
-use syn::{Ident, Lit, Meta, Variant};
+use syn::{Attribute, Error, Expr, ExprLit, Ident, Lit, LitStr, Meta, Variant};
 
-use crate::parse::{parse_u8_array_from_string, parse_u8_from_string};
+use crate::{
+    language::parse_language_code,
+    parse::{parse_u8_array_from_string, parse_u8_from_string},
+};
+
+/// The most chapters a book can have, as enforced by `ChapterNumber::new`.
+const MAX_CHAPTERS: u8 = 150;
+
+/// The most verses a chapter can have, as enforced by `VerseNumber::new`.
+const MAX_VERSES: u8 = 176;
+
+/// Attributes of the language that may be used on a book alongside the attributes of the derive.
+const BUILTIN_ATTRIBUTES: &[&str] = &[
+    "doc",
+    "cfg",
+    "cfg_attr",
+    "allow",
+    "expect",
+    "warn",
+    "deny",
+    "forbid",
+    "deprecated",
+];
 
 pub struct BookVariantData {
     pub name: Ident,
+    /// The permanent ID of the book, given by its discriminant.
+    pub id: u8,
     pub num_chapters: Option<u8>,
     pub max_verses_per_chapter: Vec<u8>,
     pub series: Option<String>,
+    /// The testament the book belongs to, as a variant of the generated `Testament` enum.
+    pub testament: Option<Ident>,
+    /// The full name of the book by language code, e.g. `("en", "1 Kings")`.
+    pub names: Vec<(String, String)>,
+    /// Abbreviations and alternative names of the book by language code.
+    pub aliases: Vec<(String, Vec<String>)>,
 }
 
 impl BookVariantData {
     pub fn from_variant(variant: &Variant) -> syn::Result<Self> {
         let name = variant.ident.clone();
+        let id = parse_id(variant)?;
         let mut num_chapters = None;
         let mut max_verses_per_chapter = Vec::new();
+        let mut verses_attr = None;
         let mut series = None;
+        let mut testament = None;
+        let mut names = Vec::new();
+        let mut aliases = Vec::new();
 
         for attr in &variant.attrs {
-            let Meta::NameValue(meta) = &attr.meta else {
-                continue;
-            };
-
-            let Some(ident) = meta.path.get_ident() else {
-                continue;
-            };
-
-            let syn::Expr::Lit(expr_lit) = &meta.value else {
-                continue;
-            };
-
-            let Lit::Str(lit_str) = &expr_lit.lit else {
-                continue;
-            };
-
-            let string_value = lit_str.value();
-
-            if ident == "chapters" {
-                num_chapters = Some(parse_u8_from_string(&string_value)?);
-            } else if ident == "verses" {
-                max_verses_per_chapter = parse_u8_array_from_string(&string_value)?;
-            } else if ident == "series" {
-                series = Some(string_value);
+            let path = attr.path();
+            if path.is_ident("names") {
+                attr.parse_nested_meta(|meta| {
+                    let code = parse_language_code(&meta.path)?;
+                    let name: LitStr = meta.value()?.parse()?;
+                    names.push((code, name.value()));
+                    Ok(())
+                })?;
+            } else if path.is_ident("aliases") {
+                attr.parse_nested_meta(|meta| {
+                    let code = parse_language_code(&meta.path)?;
+                    let list: LitStr = meta.value()?.parse()?;
+                    let list = list
+                        .value()
+                        .split(',')
+                        .map(|alias| alias.trim().to_string())
+                        .filter(|alias| !alias.is_empty())
+                        .collect();
+                    aliases.push((code, list));
+                    Ok(())
+                })?;
+            } else if path.is_ident("chapters") {
+                let lit_str = parse_string_value(attr)?;
+                let chapters = parse_u8_from_string(&lit_str.value())
+                    .map_err(|e| Error::new(lit_str.span(), e))?;
+                if !(1..=MAX_CHAPTERS).contains(&chapters) {
+                    return Err(Error::new_spanned(
+                        attr,
+                        format!("a book must have between 1 and {MAX_CHAPTERS} chapters"),
+                    ));
+                }
+                num_chapters = Some(chapters);
+            } else if path.is_ident("verses") {
+                let lit_str = parse_string_value(attr)?;
+                max_verses_per_chapter = parse_u8_array_from_string(&lit_str.value())
+                    .map_err(|e| Error::new(lit_str.span(), e))?;
+                for (chapter, &verses) in (1..).zip(&max_verses_per_chapter) {
+                    if !(1..=MAX_VERSES).contains(&verses) {
+                        return Err(Error::new_spanned(
+                            attr,
+                            format!(
+                                "chapter {chapter} has {verses} verses, but a chapter must have \
+                                 between 1 and {MAX_VERSES} verses"
+                            ),
+                        ));
+                    }
+                }
+                verses_attr = Some(attr);
+            } else if path.is_ident("series") {
+                series = Some(parse_string_value(attr)?.value());
+            } else if path.is_ident("testament") {
+                let lit_str = parse_string_value(attr)?;
+                let variant = match lit_str.value().as_str() {
+                    "old" => "Old",
+                    "new" => "New",
+                    "deuterocanon" => "Deuterocanon",
+                    _ => {
+                        return Err(Error::new_spanned(
+                            lit_str,
+                            "expected \"old\", \"new\" or \"deuterocanon\"",
+                        ));
+                    }
+                };
+                testament = Some(Ident::new(variant, lit_str.span()));
+            } else if !BUILTIN_ATTRIBUTES
+                .iter()
+                .any(|builtin| path.is_ident(builtin))
+            {
+                return Err(Error::new_spanned(attr, "unknown attribute for a book"));
             }
         }
 
-        if let Some(num_chapters) = num_chapters
-            && num_chapters as usize != max_verses_per_chapter.len()
-        {
-            return Err(syn::Error::new_spanned(
-                &variant,
+        let (Some(chapters), Some(verses_attr)) = (num_chapters, verses_attr) else {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                "a book must declare its #[chapters] and #[verses]",
+            ));
+        };
+        if testament.is_none() {
+            return Err(Error::new_spanned(
+                &variant.ident,
+                "a book must declare its #[testament]",
+            ));
+        }
+        if chapters as usize != max_verses_per_chapter.len() {
+            return Err(Error::new_spanned(
+                verses_attr,
                 "Number of chapters does not match number of verses",
             ));
         }
 
         Ok(Self {
             name,
+            id,
             num_chapters,
             max_verses_per_chapter,
             series,
+            testament,
+            names,
+            aliases,
         })
     }
+
+    /// The full name of the book in the language, if declared.
+    pub fn name(&self, code: &str) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|(language, _)| language == code)
+            .map(|(_, name)| name.as_str())
+    }
+
+    /// The abbreviations and alternative names of the book in the language.
+    pub fn aliases(&self, code: &str) -> impl Iterator<Item = &str> {
+        self.aliases
+            .iter()
+            .filter(move |(language, _)| language == code)
+            .flat_map(|(_, aliases)| aliases.iter().map(String::as_str))
+    }
+
+    /// The name and aliases of the book in the language.
+    pub fn all_names(&self, code: &str) -> impl Iterator<Item = &str> {
+        self.name(code).into_iter().chain(self.aliases(code))
+    }
+
+    /// The name of the series the book belongs to, which is the book itself if it is not part
+    /// of a series.
+    pub fn series_name(&self) -> Ident {
+        match &self.series {
+            Some(series) => Ident::new(series, self.name.span()),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Parses the book ID from the discriminant of the variant, e.g. `1` in `Genesis = 1`.
+fn parse_id(variant: &Variant) -> syn::Result<u8> {
+    match &variant.discriminant {
+        Some((
+            _,
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(id), ..
+            }),
+        )) => id.base10_parse(),
+        Some((_, expr)) => Err(Error::new_spanned(
+            expr,
+            "a book ID must be an integer literal",
+        )),
+        None => Err(Error::new_spanned(
+            &variant.ident,
+            "a book must have an explicit, permanent ID (e.g., `Genesis = 1`)",
+        )),
+    }
+}
+
+/// Parses the string of a name-value attribute, e.g. `"50"` in `#[chapters = "50"]`.
+fn parse_string_value(attr: &Attribute) -> syn::Result<&LitStr> {
+    if let Meta::NameValue(meta) = &attr.meta
+        && let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        }) = &meta.value
+    {
+        Ok(lit_str)
+    } else {
+        Err(Error::new_spanned(
+            attr,
+            "expected a string value (e.g., `#[chapters = \"50\"]`)",
+        ))
+    }
 }
@@ -0,0 +1,97 @@
+use syn::{Error, Ident, Path};
+
+/// The languages book names can be declared in, by their ISO 639-1 code. Each language is
+/// enabled by the `lang-<code>` feature of the crate deriving `Book`, so a language is only
+/// listed here once that crate has the feature.
+const LANGUAGES: &[(&str, &str)] = &[("en", "English"), ("es", "Spanish")];
+
+/// Returns the language code of the path, e.g. `en` in `#[names(en = "Genesis")]`.
+pub fn parse_language_code(path: &Path) -> syn::Result<String> {
+    let code = path
+        .get_ident()
+        .map(|ident| ident.to_string())
+        .ok_or_else(|| Error::new_spanned(path, "expected a language code"))?;
+    if LANGUAGES.iter().any(|(known, _)| *known == code) {
+        Ok(code)
+    } else {
+        Err(Error::new_spanned(
+            path,
+            format!("unsupported language '{code}'"),
+        ))
+    }
+}
+
+/// Returns the name of the `Language` variant for a language code, e.g. `English` for `en`.
+pub fn language_variant(code: &str) -> Ident {
+    let (_, name) = LANGUAGES
+        .iter()
+        .find(|(known, _)| *known == code)
+        .expect("language codes are validated when parsed");
+    Ident::new(name, proc_macro2::Span::call_site())
+}
+
+pub fn language_feature(code: &str) -> String {
+    format!("lang-{code}")
+}
+
+/// Normalizes a name the same way the generated lookups normalize their input, so "1  Kgs." and
+/// "1 kgs" find the same book.
+pub fn normalize_name(name: &str) -> String {
+    name.trim()
+        .trim_end_matches('.')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Removes the number in front of the name of a numbered book, e.g. "1 Kings" becomes "Kings".
+pub fn strip_ordinal(name: &str) -> &str {
+    name.trim_start_matches(|c: char| c.is_ascii_digit())
+        .trim_start()
+}
+
+/// Returns the number in front of the name of a numbered book, e.g. 1 in "1 Kings".
+pub fn ordinal(name: &str) -> Option<u8> {
+    let digits = name.len() - name.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    name[..digits].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_language_code() {
+        let path: Path = syn::parse_quote!(es);
+        assert_eq!(parse_language_code(&path).unwrap(), "es");
+        assert_eq!(language_variant("es"), "Spanish");
+    }
+
+    #[test]
+    fn test_parse_language_code_unsupported() {
+        let path: Path = syn::parse_quote!(xx);
+        assert!(parse_language_code(&path).is_err());
+        // no crate has a feature for French yet
+        let path: Path = syn::parse_quote!(fr);
+        assert!(parse_language_code(&path).is_err());
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("  1  Kgs. "), "1 kgs");
+        assert_eq!(normalize_name("Éxodo"), "éxodo");
+    }
+
+    #[test]
+    fn test_strip_ordinal() {
+        assert_eq!(strip_ordinal("1 Kings"), "Kings");
+        assert_eq!(strip_ordinal("Psalm 151"), "Psalm 151");
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(ordinal("3 Esdras"), Some(3));
+        assert_eq!(ordinal("Psalm 151"), None);
+    }
+}
@@ -1,7 +1,10 @@
 pub fn parse_u8_from_string(s: &str) -> syn::Result<u8> {
-    s.trim()
-        .parse::<u8>()
-        .map_err(|_| syn::Error::new_spanned(&format!("\"{}\"", s), "expected a valid u8 value"))
+    s.trim().parse::<u8>().map_err(|_| {
+        syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!("expected a valid u8 value, not \"{}\"", s.trim()),
+        )
+    })
 }
 
 pub fn parse_u8_array_from_string(s: &str) -> syn::Result<Vec<u8>> {
@@ -1,12 +1,19 @@
-#[derive(scripture_ref_derive::Book, Debug)]
+#[derive(scripture_ref_derive::Book, Debug, PartialEq)]
 enum Book {
     #[chapters = "3"]
     #[verses = "31, 25, 24"]
+    #[testament = "old"]
     Alpha = 1,
 
     #[chapters = "2"]
     #[verses = "22, 17"]
+    #[testament = "new"]
     Beta = 2,
+
+    #[chapters = "1"]
+    #[verses = "10"]
+    #[testament = "deuterocanon"]
+    Gamma = 5,
 }
 
 #[test]
@@ -39,3 +46,25 @@ fn test_verse_count() {
     assert_eq!(Book::Alpha.verse_count(), 31 + 25 + 24);
     assert_eq!(Book::Beta.verse_count(), 22 + 17);
 }
+
+#[test]
+fn test_all_books() {
+    assert_eq!(Book::COUNT, 3);
+    assert_eq!(Book::ALL, [Book::Alpha, Book::Beta, Book::Gamma]);
+}
+
+#[test]
+fn test_ids() {
+    assert_eq!(Book::Gamma.id(), 5);
+    assert_eq!(Book::from_id(2), Some(Book::Beta));
+    assert_eq!(Book::from_id(3), None);
+}
+
+#[test]
+fn test_testaments() {
+    assert_eq!(Book::OLD_TESTAMENT, [Book::Alpha]);
+    assert_eq!(Book::NEW_TESTAMENT, [Book::Beta]);
+    assert_eq!(Book::DEUTEROCANON, [Book::Gamma]);
+    assert_eq!(Book::BIBLE, [Book::Alpha, Book::Beta]);
+    assert_eq!(Book::Beta.testament(), Testament::New);
+}
@@ -21,77 +21,601 @@
 ///
 /// The IDs of the books are arbitrary, but PERMANENT. Once assigned, a book's ID should never
 /// change. This serves as a "primary key" for the book. By convention, the IDs of the first 66
-/// books match the default, protestant canon.
+/// books match the default, protestant canon. Books found only in other canons follow after.
 #[derive(scripture_ref_derive::Book, Debug, Clone, Copy, Eq, PartialEq)]
 #[repr(u8)]
-pub(crate) enum Book {
+pub enum Book {
     #[chapters = "50"]
     #[verses = "31,25,24,26,32,22,24,22,29,32,32,20,18,24,21,16,27,33,38,18,34,24,20,67,34,35,46,22,35,43,55,32,20,31,29,43,36,30,23,23,57,38,34,34,28,34,31,22,33,26"]
+    #[testament = "old"]
+    #[names(en = "Genesis", es = "Génesis")]
+    #[aliases(en = "Gen, Gn, Ge", es = "Gn, Gén")]
     Genesis = 1,
 
     #[chapters = "40"]
-    #[verses = "23,35,29,15,33,34,28,23,23,35,35,27,22,22,25,33,22,24,19,16,31,21,15,22,29,22,31,29,20,23,28,20,18,23,16,31,23,17,22,16"]
+    #[verses = "22,25,22,31,23,30,25,32,35,29,10,51,22,31,27,36,16,27,25,26,36,31,33,18,40,37,21,43,46,38,18,35,23,35,35,38,29,31,43,38"]
+    #[testament = "old"]
+    #[names(en = "Exodus", es = "Éxodo")]
+    #[aliases(en = "Exod, Ex, Exo", es = "Éx, Ex")]
     Exodus = 2,
 
+    #[chapters = "27"]
+    #[verses = "17,16,17,35,19,30,38,36,24,20,47,8,59,57,33,34,16,30,37,27,24,33,44,23,55,46,34"]
+    #[testament = "old"]
+    #[names(en = "Leviticus", es = "Levítico")]
+    #[aliases(en = "Lev, Lv", es = "Lv, Lev")]
+    Leviticus = 3,
+
+    #[chapters = "36"]
+    #[verses = "54,34,51,49,31,27,89,26,23,36,35,16,33,45,41,50,13,32,22,29,35,41,30,25,18,65,23,31,40,16,54,42,56,29,34,13"]
+    #[testament = "old"]
+    #[names(en = "Numbers", es = "Números")]
+    #[aliases(en = "Num, Nm, Nb", es = "Nm, Núm")]
+    Numbers = 4,
+
+    #[chapters = "34"]
+    #[verses = "46,37,29,49,33,25,26,20,29,22,32,32,18,29,23,22,20,22,21,20,23,30,25,22,19,19,26,68,29,20,30,52,29,12"]
+    #[testament = "old"]
+    #[names(en = "Deuteronomy", es = "Deuteronomio")]
+    #[aliases(en = "Deut, Dt", es = "Dt, Deut")]
+    Deuteronomy = 5,
+
+    #[chapters = "24"]
+    #[verses = "18,24,17,24,15,27,26,35,27,43,23,24,33,15,63,10,18,28,51,9,45,34,16,33"]
+    #[testament = "old"]
+    #[names(en = "Joshua", es = "Josué")]
+    #[aliases(en = "Josh, Jos", es = "Jos")]
+    Joshua = 6,
+
+    #[chapters = "21"]
+    #[verses = "36,23,31,24,31,40,25,35,57,18,40,15,25,20,20,31,13,31,30,48,25"]
+    #[testament = "old"]
+    #[names(en = "Judges", es = "Jueces")]
+    #[aliases(en = "Judg, Jdg", es = "Jue, Jc")]
+    Judges = 7,
+
+    #[chapters = "4"]
+    #[verses = "22,23,18,22"]
+    #[testament = "old"]
+    #[names(en = "Ruth", es = "Rut")]
+    #[aliases(en = "Rth, Ru", es = "Rt")]
+    Ruth = 8,
+
+    #[chapters = "31"]
+    #[verses = "28,36,21,22,12,21,17,22,27,27,15,25,23,52,35,23,58,30,24,42,15,23,29,22,44,25,12,25,11,31,13"]
+    #[testament = "old"]
+    #[series = "Samuel"]
+    #[names(en = "1 Samuel", es = "1 Samuel")]
+    #[aliases(en = "1 Sam, 1 Sa", es = "1 S, 1 Sam")]
+    FirstSamuel = 9,
+
+    #[chapters = "24"]
+    #[verses = "27,32,39,12,25,23,29,18,13,19,27,31,39,33,37,23,29,33,43,26,22,51,39,25"]
+    #[testament = "old"]
+    #[series = "Samuel"]
+    #[names(en = "2 Samuel", es = "2 Samuel")]
+    #[aliases(en = "2 Sam, 2 Sa", es = "2 S, 2 Sam")]
+    SecondSamuel = 10,
+
     #[chapters = "22"]
-    #[verses = "53,46,28,20,32,38,51,66,28,29,43,33,34,31,34,34,24,46,21,43,29,54"]
+    #[verses = "53,46,28,34,18,38,51,66,28,29,43,33,34,31,34,34,24,46,21,43,29,53"]
+    #[testament = "old"]
     #[series = "Kings"] // TODO: should this be a group?
+    #[names(en = "1 Kings", es = "1 Reyes")]
+    #[aliases(en = "1 Kgs, 1 Ki", es = "1 R, 1 Re")]
     FirstKings = 11,
 
+    #[chapters = "25"]
+    #[verses = "18,25,27,44,27,33,20,29,37,36,21,21,25,29,38,20,41,37,37,21,26,20,37,20,30"]
+    #[testament = "old"]
+    #[series = "Kings"]
+    #[names(en = "2 Kings", es = "2 Reyes")]
+    #[aliases(en = "2 Kgs, 2 Ki", es = "2 R, 2 Re")]
+    SecondKings = 12,
+
+    #[chapters = "29"]
+    #[verses = "54,55,24,43,26,81,40,40,44,14,47,40,14,17,29,43,27,17,19,8,30,19,32,31,31,32,34,21,30"]
+    #[testament = "old"]
+    #[series = "Chronicles"]
+    #[names(en = "1 Chronicles", es = "1 Crónicas")]
+    #[aliases(en = "1 Chr, 1 Chron", es = "1 Cr, 1 Crón")]
+    FirstChronicles = 13,
+
+    #[chapters = "36"]
+    #[verses = "17,18,17,22,14,42,22,18,31,19,23,16,22,15,19,14,19,34,11,37,20,12,21,27,28,23,9,27,36,27,21,33,25,33,27,23"]
+    #[testament = "old"]
+    #[series = "Chronicles"]
+    #[names(en = "2 Chronicles", es = "2 Crónicas")]
+    #[aliases(en = "2 Chr, 2 Chron", es = "2 Cr, 2 Crón")]
+    SecondChronicles = 14,
+
+    #[chapters = "10"]
+    #[verses = "11,70,13,24,17,22,28,36,15,44"]
+    #[testament = "old"]
+    #[names(en = "Ezra", es = "Esdras")]
+    #[aliases(en = "Ezr", es = "Esd")]
+    Ezra = 15,
+
+    #[chapters = "13"]
+    #[verses = "11,20,32,23,19,19,73,18,38,39,36,47,31"]
+    #[testament = "old"]
+    #[names(en = "Nehemiah", es = "Nehemías")]
+    #[aliases(en = "Neh, Ne", es = "Neh")]
+    Nehemiah = 16,
+
+    #[chapters = "10"]
+    #[verses = "22,23,15,17,14,14,10,17,32,3"]
+    #[testament = "old"]
+    #[names(en = "Esther", es = "Ester")]
+    #[aliases(en = "Esth, Est", es = "Est")]
+    Esther = 17,
+
+    #[chapters = "42"]
+    #[verses = "22,13,26,21,27,30,21,22,35,22,20,25,28,22,35,22,16,21,29,29,34,30,17,25,6,14,23,28,25,31,40,22,33,37,16,33,24,41,30,24,34,17"]
+    #[testament = "old"]
+    #[names(en = "Job", es = "Job")]
+    #[aliases(en = "Jb", es = "Jb")]
+    Job = 18,
+
     #[chapters = "150"]
-    #[verses = "6,11,9,9,13,11,18,10,21,18,7,9,6,7,5,11,15,51,15,10,14,32,6,10,22,11,14,9,11,13,25,11,22,23,28,13,40,23,14,18,14,12,5,27,18,12,10,15,21,23,21,11,7,9,24,14,12,12,18,14,9,13,12,11,14,20,8,36,37,6,24,20,28,23,11,13,21,72,13,20,17,8,19,13,14,17,7,19,53,17,16,16,5,23,11,13,12,9,9,5,8,29,22,35,45,48,43,14,31,7,10,10,9,8,18,19,2,29,176,7,8,9,4,8,5,6,5,6,8,8,3,18,3,3,21,26,9,8,24,14,10,8,12,15,21,10,20,14,9,6"]
+    #[verses = "6,12,8,8,12,10,17,9,20,18,7,8,6,7,5,11,15,50,14,9,13,31,6,10,22,12,14,9,11,12,24,11,22,22,28,12,40,22,13,17,13,11,5,26,17,11,9,14,20,23,19,9,6,7,23,13,11,11,17,12,8,12,11,10,13,20,7,35,36,5,24,20,28,23,10,12,20,72,13,19,16,8,18,12,13,17,7,18,52,17,16,15,5,23,11,13,12,9,9,5,8,28,22,35,45,48,43,13,31,7,10,10,9,8,18,19,2,29,176,7,8,9,4,8,5,6,5,6,8,8,3,18,3,3,21,26,9,8,24,13,10,7,12,15,21,10,20,14,9,6"]
+    #[testament = "old"]
+    #[names(en = "Psalms", es = "Salmos")]
+    #[aliases(en = "Ps, Psa, Pss, Psalm", es = "Sal")]
     Psalms = 19,
 
+    #[chapters = "31"]
+    #[verses = "33,22,35,27,23,35,27,36,18,32,31,28,25,35,33,33,28,24,29,30,31,29,35,34,28,28,27,28,27,33,31"]
+    #[testament = "old"]
+    #[names(en = "Proverbs", es = "Proverbios")]
+    #[aliases(en = "Prov, Prv, Pr", es = "Pr, Prov")]
+    Proverbs = 20,
+
+    #[chapters = "12"]
+    #[verses = "18,26,22,16,20,12,29,17,18,20,10,14"]
+    #[testament = "old"]
+    #[names(en = "Ecclesiastes", es = "Eclesiastés")]
+    #[aliases(en = "Eccl, Ecc, Qoh, Qoheleth", es = "Ec, Ecl")]
+    Ecclesiastes = 21,
+
     #[chapters = "8"]
-    #[verses = "17,17,13,16,17,15,20,14"]
+    #[verses = "17,17,11,16,16,13,13,14"]
+    #[testament = "old"]
+    #[names(en = "Song of Songs", es = "Cantares")]
+    #[aliases(
+        en = "Song, Song of Solomon, Song of Sol, Canticles, Cant",
+        es = "Cnt, Cantar de los Cantares"
+    )]
     SongOfSongs = 22,
 
+    #[chapters = "66"]
+    #[verses = "31,22,26,6,30,13,25,22,21,34,16,6,22,32,9,14,14,7,25,6,17,25,18,23,12,21,13,29,24,33,9,20,24,17,10,22,38,22,8,31,29,25,28,28,25,13,15,22,26,11,23,15,12,17,13,12,21,14,21,22,11,12,19,12,25,24"]
+    #[testament = "old"]
+    #[names(en = "Isaiah", es = "Isaías")]
+    #[aliases(en = "Isa, Is", es = "Is")]
+    Isaiah = 23,
+
+    #[chapters = "52"]
+    #[verses = "19,37,25,31,31,30,34,22,26,25,23,17,27,22,21,21,27,23,15,18,14,30,40,10,38,24,22,17,32,24,40,44,26,22,19,32,21,28,18,16,18,22,13,30,5,28,7,47,39,46,64,34"]
+    #[testament = "old"]
+    #[names(en = "Jeremiah", es = "Jeremías")]
+    #[aliases(en = "Jer, Je", es = "Jer")]
+    Jeremiah = 24,
+
+    #[chapters = "5"]
+    #[verses = "22,22,66,22,22"]
+    #[testament = "old"]
+    #[names(en = "Lamentations", es = "Lamentaciones")]
+    #[aliases(en = "Lam, La", es = "Lm, Lam")]
+    Lamentations = 25,
+
+    #[chapters = "48"]
+    #[verses = "28,10,27,17,17,14,27,18,11,22,25,28,23,23,8,63,24,32,14,49,32,31,49,27,17,21,36,26,21,26,18,32,33,31,15,38,28,23,29,49,26,20,27,31,25,24,23,35"]
+    #[testament = "old"]
+    #[names(en = "Ezekiel", es = "Ezequiel")]
+    #[aliases(en = "Ezek, Eze, Ezk", es = "Ez")]
+    Ezekiel = 26,
+
+    #[chapters = "12"]
+    #[verses = "21,49,30,37,31,28,28,27,27,21,45,13"]
+    #[testament = "old"]
+    #[names(en = "Daniel", es = "Daniel")]
+    #[aliases(en = "Dan, Dn", es = "Dn")]
+    Daniel = 27,
+
+    #[chapters = "14"]
+    #[verses = "11,23,5,19,15,11,16,14,17,15,12,14,16,9"]
+    #[testament = "old"]
+    #[names(en = "Hosea", es = "Oseas")]
+    #[aliases(en = "Hos, Ho", es = "Os")]
+    Hosea = 28,
+
+    #[chapters = "3"]
+    #[verses = "20,32,21"]
+    #[testament = "old"]
+    #[names(en = "Joel", es = "Joel")]
+    #[aliases(en = "Jl", es = "Jl")]
+    Joel = 29,
+
+    #[chapters = "9"]
+    #[verses = "15,16,15,13,27,14,17,14,15"]
+    #[testament = "old"]
+    #[names(en = "Amos", es = "Amós")]
+    #[aliases(en = "Am", es = "Am")]
+    Amos = 30,
+
     #[chapters = "1"]
     #[verses = "21"]
+    #[testament = "old"]
+    #[names(en = "Obadiah", es = "Abdías")]
+    #[aliases(en = "Obad, Ob", es = "Abd")]
     Obadiah = 31,
 
+    #[chapters = "4"]
+    #[verses = "17,10,10,11"]
+    #[testament = "old"]
+    #[names(en = "Jonah", es = "Jonás")]
+    #[aliases(en = "Jon, Jnh", es = "Jon")]
+    Jonah = 32,
+
+    #[chapters = "7"]
+    #[verses = "16,13,12,13,15,16,20"]
+    #[testament = "old"]
+    #[names(en = "Micah", es = "Miqueas")]
+    #[aliases(en = "Mic", es = "Miq")]
+    Micah = 33,
+
+    #[chapters = "3"]
+    #[verses = "15,13,19"]
+    #[testament = "old"]
+    #[names(en = "Nahum", es = "Nahúm")]
+    #[aliases(en = "Nah, Na", es = "Nah")]
+    Nahum = 34,
+
+    #[chapters = "3"]
+    #[verses = "17,20,19"]
+    #[testament = "old"]
+    #[names(en = "Habakkuk", es = "Habacuc")]
+    #[aliases(en = "Hab, Hb", es = "Hab")]
+    Habakkuk = 35,
+
+    #[chapters = "3"]
+    #[verses = "18,15,20"]
+    #[testament = "old"]
+    #[names(en = "Zephaniah", es = "Sofonías")]
+    #[aliases(en = "Zeph, Zep", es = "Sof")]
+    Zephaniah = 36,
+
+    #[chapters = "2"]
+    #[verses = "15,23"]
+    #[testament = "old"]
+    #[names(en = "Haggai", es = "Hageo")]
+    #[aliases(en = "Hag, Hg", es = "Hag")]
+    Haggai = 37,
+
+    #[chapters = "14"]
+    #[verses = "21,13,10,14,11,15,14,23,17,12,17,14,9,21"]
+    #[testament = "old"]
+    #[names(en = "Zechariah", es = "Zacarías")]
+    #[aliases(en = "Zech, Zec", es = "Zac")]
+    Zechariah = 38,
+
+    #[chapters = "4"]
+    #[verses = "14,17,18,6"]
+    #[testament = "old"]
+    #[names(en = "Malachi", es = "Malaquías")]
+    #[aliases(en = "Mal, Ml", es = "Mal")]
+    Malachi = 39,
+
     #[chapters = "28"]
     #[verses = "25,23,17,25,48,34,29,34,38,42,30,50,58,36,39,28,27,35,30,34,46,46,39,51,46,75,66,20"]
+    #[testament = "new"]
+    #[names(en = "Matthew", es = "Mateo")]
+    #[aliases(en = "Matt, Mt", es = "Mt")]
     Matthew = 40,
 
+    #[chapters = "16"]
+    #[verses = "45,28,35,41,43,56,37,38,50,52,33,44,37,72,47,20"]
+    #[testament = "new"]
+    #[names(en = "Mark", es = "Marcos")]
+    #[aliases(en = "Mk, Mrk", es = "Mc, Mr")]
+    Mark = 41,
+
+    #[chapters = "24"]
+    #[verses = "80,52,38,44,39,49,50,56,62,42,54,59,35,35,32,31,37,43,48,47,38,71,56,53"]
+    #[testament = "new"]
+    #[names(en = "Luke", es = "Lucas")]
+    #[aliases(en = "Lk, Luk", es = "Lc")]
+    Luke = 42,
+
     #[chapters = "21"]
     #[verses = "51,25,36,54,47,71,53,59,41,42,57,50,38,31,27,33,26,40,42,31,25"]
+    #[testament = "new"]
+    #[names(en = "John", es = "Juan")]
+    #[aliases(en = "Jn, Jhn", es = "Jn")]
     John = 43,
 
+    #[chapters = "28"]
+    #[verses = "26,47,26,37,42,15,60,40,43,48,30,25,52,28,41,40,34,28,41,38,40,30,35,27,27,32,44,31"]
+    #[testament = "new"]
+    #[names(en = "Acts", es = "Hechos")]
+    #[aliases(en = "Act, Ac, Acts of the Apostles", es = "Hch")]
+    Acts = 44,
+
+    #[chapters = "16"]
+    #[verses = "32,29,31,25,21,23,25,39,33,21,36,21,14,23,33,27"]
+    #[testament = "new"]
+    #[names(en = "Romans", es = "Romanos")]
+    #[aliases(en = "Rom, Rm", es = "Ro, Rom")]
+    Romans = 45,
+
+    #[chapters = "16"]
+    #[verses = "31,16,23,21,13,20,40,13,27,33,34,31,13,40,58,24"]
+    #[testament = "new"]
+    #[series = "Corinthians"]
+    #[names(en = "1 Corinthians", es = "1 Corintios")]
+    #[aliases(en = "1 Cor", es = "1 Co")]
+    FirstCorinthians = 46,
+
+    #[chapters = "13"]
+    #[verses = "24,17,18,18,21,18,16,24,15,18,33,21,14"]
+    #[testament = "new"]
+    #[series = "Corinthians"]
+    #[names(en = "2 Corinthians", es = "2 Corintios")]
+    #[aliases(en = "2 Cor", es = "2 Co")]
+    SecondCorinthians = 47,
+
+    #[chapters = "6"]
+    #[verses = "24,21,29,31,26,18"]
+    #[testament = "new"]
+    #[names(en = "Galatians", es = "Gálatas")]
+    #[aliases(en = "Gal", es = "Gá, Gál")]
+    Galatians = 48,
+
+    #[chapters = "6"]
+    #[verses = "23,22,21,32,33,24"]
+    #[testament = "new"]
+    #[names(en = "Ephesians", es = "Efesios")]
+    #[aliases(en = "Eph", es = "Ef")]
+    Ephesians = 49,
+
+    #[chapters = "4"]
+    #[verses = "30,30,21,23"]
+    #[testament = "new"]
+    #[names(en = "Philippians", es = "Filipenses")]
+    #[aliases(en = "Phil, Php", es = "Flp, Fil")]
+    Philippians = 50,
+
+    #[chapters = "4"]
+    #[verses = "29,23,25,18"]
+    #[testament = "new"]
+    #[names(en = "Colossians", es = "Colosenses")]
+    #[aliases(en = "Col", es = "Col")]
+    Colossians = 51,
+
+    #[chapters = "5"]
+    #[verses = "10,20,13,18,28"]
+    #[testament = "new"]
+    #[series = "Thessalonians"]
+    #[names(en = "1 Thessalonians", es = "1 Tesalonicenses")]
+    #[aliases(en = "1 Thess, 1 Th", es = "1 Ts")]
+    FirstThessalonians = 52,
+
+    #[chapters = "3"]
+    #[verses = "12,17,18"]
+    #[testament = "new"]
+    #[series = "Thessalonians"]
+    #[names(en = "2 Thessalonians", es = "2 Tesalonicenses")]
+    #[aliases(en = "2 Thess, 2 Th", es = "2 Ts")]
+    SecondThessalonians = 53,
+
+    #[chapters = "6"]
+    #[verses = "20,15,16,16,25,21"]
+    #[testament = "new"]
+    #[series = "Timothy"]
+    #[names(en = "1 Timothy", es = "1 Timoteo")]
+    #[aliases(en = "1 Tim, 1 Tm", es = "1 Ti")]
+    FirstTimothy = 54,
+
+    #[chapters = "4"]
+    #[verses = "18,26,17,22"]
+    #[testament = "new"]
+    #[series = "Timothy"]
+    #[names(en = "2 Timothy", es = "2 Timoteo")]
+    #[aliases(en = "2 Tim, 2 Tm", es = "2 Ti")]
+    SecondTimothy = 55,
+
+    #[chapters = "3"]
+    #[verses = "16,15,15"]
+    #[testament = "new"]
+    #[names(en = "Titus", es = "Tito")]
+    #[aliases(en = "Tit", es = "Tit")]
+    Titus = 56,
+
     #[chapters = "1"]
-    #[verses = "15"]
+    #[verses = "25"]
+    #[testament = "new"]
+    #[names(en = "Philemon", es = "Filemón")]
+    #[aliases(en = "Phlm, Phm", es = "Flm")]
+    Philemon = 57,
+
+    #[chapters = "13"]
+    #[verses = "14,18,19,16,14,20,28,13,28,39,40,29,25"]
+    #[testament = "new"]
+    #[names(en = "Hebrews", es = "Hebreos")]
+    #[aliases(en = "Heb", es = "Heb")]
+    Hebrews = 58,
+
+    #[chapters = "5"]
+    #[verses = "27,26,18,17,20"]
+    #[testament = "new"]
+    #[names(en = "James", es = "Santiago")]
+    #[aliases(en = "Jas, Jm", es = "Stg")]
+    James = 59,
+
+    #[chapters = "5"]
+    #[verses = "25,25,22,19,14"]
+    #[testament = "new"]
+    #[series = "Peter"]
+    #[names(en = "1 Peter", es = "1 Pedro")]
+    #[aliases(en = "1 Pet, 1 Pt", es = "1 P")]
+    FirstPeter = 60,
+
+    #[chapters = "3"]
+    #[verses = "21,22,18"]
+    #[testament = "new"]
+    #[series = "Peter"]
+    #[names(en = "2 Peter", es = "2 Pedro")]
+    #[aliases(en = "2 Pet, 2 Pt", es = "2 P")]
+    SecondPeter = 61,
+
+    #[chapters = "5"]
+    #[verses = "10,29,24,21,21"]
+    #[testament = "new"]
+    #[series = "John"]
+    #[names(en = "1 John", es = "1 Juan")]
+    #[aliases(en = "1 Jn, 1 Jhn", es = "1 Jn")]
+    FirstJohn = 62,
+
+    #[chapters = "1"]
+    #[verses = "13"]
+    #[testament = "new"]
     #[series = "John"]
+    #[names(en = "2 John", es = "2 Juan")]
+    #[aliases(en = "2 Jn, 2 Jhn", es = "2 Jn")]
+    SecondJohn = 63,
+
+    #[chapters = "1"]
+    #[verses = "14"]
+    #[testament = "new"]
+    #[series = "John"]
+    #[names(en = "3 John", es = "3 Juan")]
+    #[aliases(en = "3 Jn, 3 Jhn", es = "3 Jn")]
     ThirdJohn = 64,
 
+    #[chapters = "1"]
+    #[verses = "25"]
+    #[testament = "new"]
+    #[names(en = "Jude", es = "Judas")]
+    #[aliases(en = "Jud", es = "Jud")]
+    Jude = 65,
+
     #[chapters = "22"]
-    #[verses = "20,29,22,11,14,17,17,13,21,11,19,18,18,20,8,21,18,24,21,15,27,21"]
+    #[verses = "20,29,22,11,14,17,17,13,21,11,19,17,18,20,8,21,18,24,21,15,27,21"]
+    #[testament = "new"]
+    #[names(en = "Revelation", es = "Apocalipsis")]
+    #[aliases(en = "Rev, Rv, Apocalypse, Revelations", es = "Ap")]
     Revelation = 66,
+
+    // Books outside the protestant canon. Baruch includes the Letter of Jeremiah as its sixth
+    // chapter, as in the Vulgate.
+    // TODO: the Greek additions to Esther and Daniel are not modelled yet
+    #[chapters = "14"]
+    #[verses = "22,14,17,21,23,19,18,21,6,14,19,22,18,15"]
+    #[testament = "deuterocanon"]
+    #[names(en = "Tobit", es = "Tobías")]
+    #[aliases(en = "Tob, Tb", es = "Tob")]
+    Tobit = 67,
+
+    #[chapters = "16"]
+    #[verses = "16,28,10,15,24,21,32,36,14,23,23,20,20,19,14,25"]
+    #[testament = "deuterocanon"]
+    #[names(en = "Judith", es = "Judit")]
+    #[aliases(en = "Jdt", es = "Jdt")]
+    Judith = 68,
+
+    #[chapters = "16"]
+    #[verses = "64,70,60,61,68,63,50,32,73,89,74,53,53,49,41,24"]
+    #[testament = "deuterocanon"]
+    #[series = "Maccabees"]
+    #[names(en = "1 Maccabees", es = "1 Macabeos")]
+    #[aliases(en = "1 Macc, 1 Ma", es = "1 Mac")]
+    FirstMaccabees = 69,
+
+    #[chapters = "15"]
+    #[verses = "36,32,40,50,27,31,42,36,29,38,38,46,26,46,39"]
+    #[testament = "deuterocanon"]
+    #[series = "Maccabees"]
+    #[names(en = "2 Maccabees", es = "2 Macabeos")]
+    #[aliases(en = "2 Macc, 2 Ma", es = "2 Mac")]
+    SecondMaccabees = 70,
+
+    #[chapters = "19"]
+    #[verses = "16,24,19,20,23,25,30,21,18,21,26,27,19,31,19,29,21,25,22"]
+    #[testament = "deuterocanon"]
+    #[names(en = "Wisdom", es = "Sabiduría")]
+    #[aliases(en = "Wis, Wisdom of Solomon", es = "Sab")]
+    Wisdom = 71,
+
+    #[chapters = "51"]
+    #[verses = "30,18,31,31,15,37,36,19,18,31,34,18,26,27,20,30,32,33,30,32,28,27,28,34,26,29,30,26,28,25,31,24,33,31,26,31,31,34,35,30,27,25,35,23,26,20,25,25,16,29,30"]
+    #[testament = "deuterocanon"]
+    #[names(en = "Sirach", es = "Eclesiástico")]
+    #[aliases(en = "Sir, Ecclesiasticus", es = "Eclo, Sirácida")]
+    Sirach = 72,
+
+    #[chapters = "6"]
+    #[verses = "22,35,38,37,9,73"]
+    #[testament = "deuterocanon"]
+    #[names(en = "Baruch", es = "Baruc")]
+    #[aliases(en = "Bar", es = "Bar")]
+    Baruch = 73,
+
+    #[chapters = "9"]
+    #[verses = "58,30,24,63,73,34,15,96,55"]
+    #[testament = "deuterocanon"]
+    #[series = "Esdras"]
+    #[names(en = "1 Esdras", es = "3 Esdras")]
+    #[aliases(en = "1 Esd", es = "3 Esd")]
+    FirstEsdras = 74,
+
+    #[chapters = "7"]
+    #[verses = "29,33,30,21,51,41,23"]
+    #[testament = "deuterocanon"]
+    #[series = "Maccabees"]
+    #[names(en = "3 Maccabees", es = "3 Macabeos")]
+    #[aliases(en = "3 Macc, 3 Ma", es = "3 Mac")]
+    ThirdMaccabees = 75,
+
+    #[chapters = "1"]
+    #[verses = "15"]
+    #[testament = "deuterocanon"]
+    #[names(en = "Prayer of Manasseh", es = "Oración de Manasés")]
+    #[aliases(en = "Pr Man, PrMan", es = "Man")]
+    PrayerOfManasseh = 76,
+
+    #[chapters = "1"]
+    #[verses = "7"]
+    #[testament = "deuterocanon"]
+    #[names(en = "Psalm 151", es = "Salmo 151")]
+    #[aliases(en = "Ps 151", es = "Sal 151")]
+    Psalm151 = 77,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub(crate) struct ChapterNumber(u8);
+pub struct ChapterNumber(u8);
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub(crate) struct Chapter {
+pub struct Chapter {
     pub(crate) book: Book,
     pub(crate) number: ChapterNumber,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub(crate) struct VerseNumber(u8);
+pub struct VerseNumber(u8);
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub(crate) struct Verse {
+pub struct Verse {
     pub(crate) book: Book,
     pub(crate) chapter: Chapter,
     pub(crate) number: VerseNumber,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub(crate) struct VersePartLabel(u8);
+pub struct VersePartLabel(u8);
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub(crate) struct VersePart {
+pub struct VersePart {
     pub(crate) book: Book,
     pub(crate) chapter: Chapter,
     pub(crate) verse: Verse,
@@ -99,10 +623,10 @@ pub(crate) struct VersePart {
 }
 
 #[derive(Debug)]
-pub(crate) struct ScripturePosition(u32);
+pub struct ScripturePosition(u32);
 
 impl ScripturePosition {
-    pub(crate) fn new(
+    pub fn new(
         book: Book,
         chapter: ChapterNumber,
         verse: VerseNumber,
@@ -115,12 +639,12 @@ impl ScripturePosition {
         Self(position)
     }
 
-    pub(crate) fn get(&self) -> u32 {
+    pub fn get(&self) -> u32 {
         self.0
     }
 }
 
-pub(crate) trait HasBook {
+pub trait HasBook {
     fn book(&self) -> Book;
 }
 
@@ -148,7 +672,7 @@ impl HasBook for VersePart {
     }
 }
 
-pub(crate) trait Spanned {
+pub trait Spanned {
     type Position;
     type Error;
     fn start(&self) -> Result<Self::Position, Self::Error>;
@@ -231,34 +755,6 @@ impl Spanned for Verse {
 }
 
 impl Book {
-    const OLD_TESTAMENT: [Self; 5] = [
-        // TODO: update to 39
-        Self::Genesis,
-        Self::Exodus,
-        Self::FirstKings,
-        Self::SongOfSongs,
-        Self::Obadiah,
-    ];
-
-    const NEW_TESTAMENT: [Self; 1] = [Self::Matthew]; // TODO: update to 27
-
-    const BIBLE: [Self; 6] = {
-        // TODO: update to 66
-        let mut all = [Book::Genesis; 6];
-        let mut i = 0;
-        while i < Self::OLD_TESTAMENT.len() {
-            all[i] = Self::OLD_TESTAMENT[i];
-            i += 1;
-        }
-        let mut j = 0;
-        while j < Self::NEW_TESTAMENT.len() {
-            all[i] = Self::NEW_TESTAMENT[j];
-            i += 1;
-            j += 1;
-        }
-        all
-    };
-
     pub fn old_testament() -> &'static [Self] {
         &Self::OLD_TESTAMENT
     }
@@ -270,10 +766,16 @@ impl Book {
     pub fn bible() -> &'static [Self] {
         &Self::BIBLE
     }
+
+    /// Whether the book has a single chapter, like Jude, whose references number only verses,
+    /// e.g. "Jude 5".
+    pub fn has_one_chapter(&self) -> bool {
+        self.chapter_count() == 1
+    }
 }
 
 impl ChapterNumber {
-    pub(crate) fn new(value: u8) -> Result<Self, String> {
+    pub fn new(value: u8) -> Result<Self, String> {
         if !(1u8..=150u8).contains(&value) {
             Err(format!(
                 "chapter {value} is out of range; must be positive and not greater than 150"
@@ -283,14 +785,16 @@ impl ChapterNumber {
         }
     }
 
-    pub(crate) fn get(&self) -> u8 {
+    pub fn get(&self) -> u8 {
         self.0
     }
 }
 
 impl Chapter {
     pub fn new(book: Book, number: ChapterNumber) -> Result<Self, String> {
-        if book.chapter_count() < number.get() {
+        if book.has_one_chapter() && number.get() > 1 {
+            Err(format!("{book} has only one chapter, not {}", number.get()))
+        } else if book.chapter_count() < number.get() {
             Err(format!(
                 "{book} has {} chapters, not {}",
                 book.chapter_count(),
@@ -308,7 +812,7 @@ impl Chapter {
 }
 
 impl VerseNumber {
-    pub(crate) fn new(value: u8) -> Result<Self, String> {
+    pub fn new(value: u8) -> Result<Self, String> {
         if !(1u8..=176u8).contains(&value) {
             Err(format!(
                 "verse {value} out of range; must be positive and not greater than 176"
@@ -318,14 +822,14 @@ impl VerseNumber {
         }
     }
 
-    pub(crate) fn get(&self) -> u8 {
+    pub fn get(&self) -> u8 {
         self.0
     }
 }
 
 // TODO: should this be more similar to the Verse type?
 impl VersePartLabel {
-    pub(crate) fn new(value: u8) -> Result<Self, String> {
+    pub fn new(value: u8) -> Result<Self, String> {
         if !(b'a'..=b'd').contains(&value) {
             Err(format!(
                 "verse phrase {value} is not valid, must be a single letter from a to d"
@@ -335,11 +839,11 @@ impl VersePartLabel {
         }
     }
 
-    pub(crate) fn get(&self) -> u8 {
+    pub fn get(&self) -> u8 {
         self.0
     }
 
-    pub(crate) fn max() -> Self {
+    pub fn max() -> Self {
         Self(b'd') // TODO: share max logic
     }
 }
@@ -388,24 +892,6 @@ impl std::fmt::Display for VerseNumber {
     }
 }
 
-impl std::fmt::Display for Book {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // This is treated as code so it's english-only, like error messages.
-        match self {
-            Book::Genesis => write!(f, "Genesis"),
-            Book::Exodus => write!(f, "Exodus"),
-            Book::FirstKings => write!(f, "1 Kings"),
-            Book::Psalms => write!(f, "Psalms"),
-            Book::SongOfSongs => write!(f, "Song of Songs"),
-            Book::Obadiah => write!(f, "Obadiah"),
-            Book::Matthew => write!(f, "Matthew"),
-            Book::John => write!(f, "John"),
-            Book::ThirdJohn => write!(f, "3 John"),
-            Book::Revelation => write!(f, "Revelation"),
-        }
-    }
-}
-
 impl std::fmt::Display for Chapter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} {}", self.book, self.number)
@@ -414,34 +900,21 @@ impl std::fmt::Display for Chapter {
 
 impl std::fmt::Display for Verse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}:{}", self.book, self.chapter.number, self.number)
+        let book = self.book.to_string();
+        if book.ends_with(|c: char| c.is_ascii_digit()) {
+            // a number after the name would read as part of it, e.g. "Psalm 151 4"
+            write!(f, "{book}:{}", self.number)
+        } else if self.book.has_one_chapter() {
+            write!(f, "{book} {}", self.number)
+        } else {
+            write!(f, "{book} {}:{}", self.chapter.number, self.number)
+        }
     }
 }
 
 impl std::fmt::Display for VersePartLabel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0.to_ascii_uppercase()) // TODO: is this right?
-    }
-}
-
-impl TryFrom<&str> for Book {
-    type Error = String;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let normalized = value.to_lowercase(); // TODO: Avoid allocation
-
-        #[cfg(feature = "lang-en")]
-        match normalized.as_str() {
-            "genesis" | "gen" | "gn" => Ok(Book::Genesis),
-            "1 kings" => Ok(Book::FirstKings),
-            "song of songs" | "song of solomon" => Ok(Book::SongOfSongs),
-            "obadiah" => Ok(Book::Obadiah),
-            "matthew" => Ok(Book::Matthew),
-            _ => Err(format!("not a valid book: {}", value)),
-        }
-
-        #[cfg(not(any(feature = "lang-en")))]
-        compile_error!("at least one language feature must be enabled (e.g., lang-en)");
+        write!(f, "{}", self.0 as char)
     }
 }
 
@@ -468,6 +941,18 @@ impl TryFrom<&str> for ChapterNumber {
     }
 }
 
+impl TryFrom<char> for VersePartLabel {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        u8::try_from(value)
+            .map_err(|_| {
+                format!("verse phrase {value} is not valid, must be a single letter from a to d")
+            })
+            .and_then(VersePartLabel::new)
+    }
+}
+
 impl TryFrom<u8> for VerseNumber {
     type Error = String;
 
@@ -497,11 +982,10 @@ impl<'de> TryFrom<(Option<u8>, &'de crate::bvc::BookSeries)> for Book {
     fn try_from(
         (book_num, book_token): (Option<u8>, &'de crate::bvc::BookSeries),
     ) -> Result<Self, Self::Error> {
-        let token = match book_num {
-            Some(n) => format!("{} {}", n, book_token),
-            None => format!("{}", book_token),
-        };
-        Book::try_from(token.as_str())
+        Book::from_series(book_num, *book_token).ok_or_else(|| match book_num {
+            Some(n) => format!("not a valid book: {} {}", n, book_token),
+            None => format!("not a valid book: {}", book_token),
+        })
     }
 }
 
@@ -548,4 +1032,168 @@ mod tests {
             prop_assert!(VerseNumber::new(n).is_err());
         }
     }
+
+    #[test]
+    fn testaments_contain_every_book_in_order() {
+        assert_eq!(Book::old_testament().len(), 39);
+        assert_eq!(Book::new_testament().len(), 27);
+        assert_eq!(Book::bible().len(), 66);
+        for (i, book) in Book::bible().iter().enumerate() {
+            assert_eq!(*book as usize, i + 1);
+        }
+        assert_eq!(Book::old_testament().last(), Some(&Book::Malachi));
+        assert_eq!(Book::new_testament().first(), Some(&Book::Matthew));
+    }
+
+    #[test]
+    fn books_are_found_by_their_permanent_ids() {
+        assert_eq!(Book::COUNT, 77);
+        for book in Book::ALL {
+            assert_eq!(Book::from_id(book.id()), Some(book));
+            assert_eq!(book.id(), book as u8);
+        }
+        assert_eq!(Book::from_id(0), None);
+        assert_eq!(Book::from_id(40), Some(Book::Matthew));
+        assert_eq!(Book::Tobit.testament(), Testament::Deuterocanon);
+        assert_eq!(Book::DEUTEROCANON.len(), 11);
+    }
+
+    #[test]
+    fn books_are_found_by_their_number_and_series() {
+        assert_eq!(
+            Book::from_series(Some(1), BookSeries::Kings),
+            Some(Book::FirstKings)
+        );
+        assert_eq!(
+            Book::from_series(Some(3), BookSeries::John),
+            Some(Book::ThirdJohn)
+        );
+        assert_eq!(Book::from_series(None, BookSeries::John), Some(Book::John));
+        assert_eq!(Book::from_series(None, BookSeries::Kings), None);
+        assert_eq!(Book::from_series(Some(4), BookSeries::John), None);
+    }
+
+    #[test]
+    fn verse_tables_match_chapter_counts() {
+        for book in Book::bible() {
+            assert_eq!(
+                book.max_verse_count_by_chapter().len(),
+                book.chapter_count() as usize,
+                "{book}"
+            );
+        }
+    }
+
+    #[test]
+    fn verse_tables_count_the_verses_of_the_king_james_version() {
+        let total = |books: &[Book]| -> u32 {
+            books
+                .iter()
+                .flat_map(|book| book.max_verse_count_by_chapter())
+                .map(|&verses| u32::from(verses))
+                .sum()
+        };
+        assert_eq!(total(Book::old_testament()), 23145);
+        assert_eq!(total(Book::new_testament()), 7957);
+        assert_eq!(total(Book::bible()), 31102);
+    }
+
+    #[test]
+    fn every_chapter_accepts_its_last_verse() {
+        for book in Book::bible() {
+            for chapter in 1..=book.chapter_count() {
+                let last_verse = book.max_verses_in_chapter(chapter).unwrap();
+                let chapter = ChapterNumber::new(chapter).unwrap();
+                assert!(Verse::new(*book, chapter, VerseNumber::new(last_verse).unwrap()).is_ok());
+                if let Ok(past_last) = VerseNumber::new(last_verse + 1) {
+                    assert!(Verse::new(*book, chapter, past_last).is_err());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validates_verses_outside_the_original_books() {
+        let valid = |book, chapter, verse| {
+            Verse::new(
+                book,
+                ChapterNumber::new(chapter).unwrap(),
+                VerseNumber::new(verse).unwrap(),
+            )
+            .is_ok()
+        };
+        assert!(valid(Book::Leviticus, 27, 34));
+        assert!(valid(Book::Malachi, 4, 6));
+        assert!(!valid(Book::Malachi, 5, 1));
+        assert!(valid(Book::Romans, 16, 27));
+        assert!(!valid(Book::Jude, 1, 26));
+        assert!(valid(Book::Psalms, 119, 176));
+        assert!(!valid(Book::Psalms, 117, 3));
+    }
+
+    #[test]
+    fn parses_full_book_names() {
+        assert_eq!(Book::try_from("Leviticus"), Ok(Book::Leviticus));
+        assert_eq!(Book::try_from("2 corinthians"), Ok(Book::SecondCorinthians));
+        assert_eq!(Book::try_from("1 John"), Ok(Book::FirstJohn));
+        assert!(Book::try_from("4 John").is_err());
+    }
+
+    #[test]
+    fn parses_abbreviations() {
+        assert_eq!(Book::try_from("Gen"), Ok(Book::Genesis));
+        assert_eq!(Book::try_from("1 kgs."), Ok(Book::FirstKings));
+        assert_eq!(Book::try_from("Song  of Solomon"), Ok(Book::SongOfSongs));
+        assert_eq!(Book::try_from("Rev"), Ok(Book::Revelation));
+    }
+
+    #[test]
+    fn every_book_is_named_in_every_language() {
+        for language in Language::ENABLED {
+            for book in Book::bible() {
+                assert_eq!(Book::try_from(book.name(*language)), Ok(*book));
+                for alias in book.aliases(*language) {
+                    assert_eq!(Book::try_from(*alias), Ok(*book), "{alias}");
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn parses_spanish_names() {
+        assert_eq!(Book::try_from("Génesis"), Ok(Book::Genesis));
+        assert_eq!(Book::try_from("1 reyes"), Ok(Book::FirstKings));
+        assert_eq!(Book::try_from("Apocalipsis"), Ok(Book::Revelation));
+        assert_eq!(Book::FirstKings.name(Language::Spanish), "1 Reyes");
+    }
+
+    #[test]
+    fn book_series_round_trip_through_their_names() {
+        for book in Book::bible() {
+            let series = BookSeries::from_book(book);
+            assert_eq!(series.to_string().parse(), Ok(series));
+            assert_eq!(series.to_string().to_uppercase().parse(), Ok(series));
+        }
+        assert_eq!(BookSeries::SongOfSongs.to_string(), "Song of Songs");
+        assert_eq!(BookSeries::Kings.to_string(), "Kings");
+    }
+
+    #[test]
+    fn verses_of_single_chapter_books_omit_the_chapter() {
+        let one = ChapterNumber::new(1).unwrap();
+        let jude = Verse::new(Book::Jude, one, VerseNumber::new(5).unwrap()).unwrap();
+        assert_eq!(jude.to_string(), "Jude 5");
+        let john = Verse::new(Book::John, one, VerseNumber::new(5).unwrap()).unwrap();
+        assert_eq!(john.to_string(), "John 1:5");
+        assert_eq!(
+            Chapter::new(Book::Obadiah, ChapterNumber::new(5).unwrap()),
+            Err("Obadiah has only one chapter, not 5".to_string())
+        );
+        let single_chapter_books = Book::bible()
+            .iter()
+            .filter(|book| book.has_one_chapter())
+            .count();
+        assert_eq!(single_chapter_books, 5);
+    }
 }
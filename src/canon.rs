@@ -1,59 +1,611 @@
+use std::cmp::Ordering;
+
 use crate::Book;
 
+/// The 66 books of the protestant canon, in the traditional English order.
+#[derive(Debug, Clone, Copy)]
+pub struct ProtestantCanon;
+
+/// The 73 books of the Catholic canon, including the deuterocanonical books of the Vulgate.
+#[derive(Debug, Clone, Copy)]
+pub struct CatholicCanon;
+
+/// The books of the Eastern Orthodox canon, in the order of the Septuagint.
+///
+/// Orthodox churches differ slightly on the contents of the canon. This follows the Greek
+/// tradition, which includes 1 Esdras, 3 Maccabees, the Prayer of Manasseh and Psalm 151.
+#[derive(Debug, Clone, Copy)]
+pub struct OrthodoxCanon;
+
+/// The books of the Hebrew Bible in the order of the Tanakh: the Torah, the Nevi'im (Prophets)
+/// and the Ketuvim (Writings), ending with Chronicles.
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct ProtestantCanon;
+pub struct TanakhCanon;
+
+/// A canon defined at runtime, e.g. a lectionary's subset of books or a scholarly edition's
+/// order.
+///
+/// Canons can be parsed from a list of book names separated by newlines or commas. Blank lines
+/// and lines starting with `#` are ignored, so the list can be kept in a configuration file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DynamicCanon {
+    books: Vec<Book>,
+}
 
-pub(crate) trait Canonical {
-    const ORDERED_BOOKS: &'static [Book];
+/// The ordered books of a canon.
+///
+/// The trait is object safe, so canons known at compile time and canons built at runtime can be
+/// used interchangeably through `&dyn Canonical`.
+pub trait Canonical {
+    fn ordered_books(&self) -> &[Book];
 
-    fn ordered_books(&self) -> &'static [Book];
-    fn book_position(&self, book: Book) -> Option<u8>;
-    fn book_at_position(&self, position: u8) -> Option<Book>;
+    fn book_position(&self, book: Book) -> Option<u8> {
+        self.ordered_books()
+            .iter()
+            .position(|b| *b == book)
+            .map(|b| {
+                u8::try_from(b).expect(
+                    "position should fit in u8 as it is bounded by the number of existing books",
+                )
+            })
+    }
+
+    fn book_at_position(&self, position: u8) -> Option<Book> {
+        self.ordered_books().get(position as usize).copied()
+    }
+
+    /// Orders two books by their position in the canon. Books outside the canon are unordered.
+    fn compare_books(&self, a: Book, b: Book) -> Option<Ordering> {
+        Some(self.book_position(a)?.cmp(&self.book_position(b)?))
+    }
     // fn to_canon(&self) -> Canon;
 }
 
+impl ProtestantCanon {
+    const ORDERED_BOOKS: &'static [Book] = &[
+        Book::Genesis,
+        Book::Exodus,
+        Book::Leviticus,
+        Book::Numbers,
+        Book::Deuteronomy,
+        Book::Joshua,
+        Book::Judges,
+        Book::Ruth,
+        Book::FirstSamuel,
+        Book::SecondSamuel,
+        Book::FirstKings,
+        Book::SecondKings,
+        Book::FirstChronicles,
+        Book::SecondChronicles,
+        Book::Ezra,
+        Book::Nehemiah,
+        Book::Esther,
+        Book::Job,
+        Book::Psalms,
+        Book::Proverbs,
+        Book::Ecclesiastes,
+        Book::SongOfSongs,
+        Book::Isaiah,
+        Book::Jeremiah,
+        Book::Lamentations,
+        Book::Ezekiel,
+        Book::Daniel,
+        Book::Hosea,
+        Book::Joel,
+        Book::Amos,
+        Book::Obadiah,
+        Book::Jonah,
+        Book::Micah,
+        Book::Nahum,
+        Book::Habakkuk,
+        Book::Zephaniah,
+        Book::Haggai,
+        Book::Zechariah,
+        Book::Malachi,
+        Book::Matthew,
+        Book::Mark,
+        Book::Luke,
+        Book::John,
+        Book::Acts,
+        Book::Romans,
+        Book::FirstCorinthians,
+        Book::SecondCorinthians,
+        Book::Galatians,
+        Book::Ephesians,
+        Book::Philippians,
+        Book::Colossians,
+        Book::FirstThessalonians,
+        Book::SecondThessalonians,
+        Book::FirstTimothy,
+        Book::SecondTimothy,
+        Book::Titus,
+        Book::Philemon,
+        Book::Hebrews,
+        Book::James,
+        Book::FirstPeter,
+        Book::SecondPeter,
+        Book::FirstJohn,
+        Book::SecondJohn,
+        Book::ThirdJohn,
+        Book::Jude,
+        Book::Revelation,
+    ];
+}
+
 impl Canonical for ProtestantCanon {
+    fn ordered_books(&self) -> &[Book] {
+        Self::ORDERED_BOOKS
+    }
+
+    // fn to_canon(&self) -> Canon {
+    //     Canon::Protestant
+    // }
+}
+
+impl CatholicCanon {
     const ORDERED_BOOKS: &'static [Book] = &[
         Book::Genesis,
         Book::Exodus,
+        Book::Leviticus,
+        Book::Numbers,
+        Book::Deuteronomy,
+        Book::Joshua,
+        Book::Judges,
+        Book::Ruth,
+        Book::FirstSamuel,
+        Book::SecondSamuel,
         Book::FirstKings,
+        Book::SecondKings,
+        Book::FirstChronicles,
+        Book::SecondChronicles,
+        Book::Ezra,
+        Book::Nehemiah,
+        Book::Tobit,
+        Book::Judith,
+        Book::Esther,
+        Book::FirstMaccabees,
+        Book::SecondMaccabees,
+        Book::Job,
+        Book::Psalms,
+        Book::Proverbs,
+        Book::Ecclesiastes,
         Book::SongOfSongs,
+        Book::Wisdom,
+        Book::Sirach,
+        Book::Isaiah,
+        Book::Jeremiah,
+        Book::Lamentations,
+        Book::Baruch,
+        Book::Ezekiel,
+        Book::Daniel,
+        Book::Hosea,
+        Book::Joel,
+        Book::Amos,
         Book::Obadiah,
+        Book::Jonah,
+        Book::Micah,
+        Book::Nahum,
+        Book::Habakkuk,
+        Book::Zephaniah,
+        Book::Haggai,
+        Book::Zechariah,
+        Book::Malachi,
         Book::Matthew,
+        Book::Mark,
+        Book::Luke,
+        Book::John,
+        Book::Acts,
+        Book::Romans,
+        Book::FirstCorinthians,
+        Book::SecondCorinthians,
+        Book::Galatians,
+        Book::Ephesians,
+        Book::Philippians,
+        Book::Colossians,
+        Book::FirstThessalonians,
+        Book::SecondThessalonians,
+        Book::FirstTimothy,
+        Book::SecondTimothy,
+        Book::Titus,
+        Book::Philemon,
+        Book::Hebrews,
+        Book::James,
+        Book::FirstPeter,
+        Book::SecondPeter,
+        Book::FirstJohn,
+        Book::SecondJohn,
+        Book::ThirdJohn,
+        Book::Jude,
         Book::Revelation,
     ];
+}
 
-    fn ordered_books(&self) -> &'static [Book] {
+impl Canonical for CatholicCanon {
+    fn ordered_books(&self) -> &[Book] {
         Self::ORDERED_BOOKS
     }
+}
 
-    fn book_position(&self, book: Book) -> Option<u8> {
-        self.ordered_books()
-            .iter()
-            .position(|b| *b == book)
-            .map(|b| {
-                u8::try_from(b).expect(
-                    "position should fit in u8 as it is bounded by the number of existing books",
-                )
-            })
+impl OrthodoxCanon {
+    const ORDERED_BOOKS: &'static [Book] = &[
+        Book::Genesis,
+        Book::Exodus,
+        Book::Leviticus,
+        Book::Numbers,
+        Book::Deuteronomy,
+        Book::Joshua,
+        Book::Judges,
+        Book::Ruth,
+        Book::FirstSamuel,
+        Book::SecondSamuel,
+        Book::FirstKings,
+        Book::SecondKings,
+        Book::FirstChronicles,
+        Book::SecondChronicles,
+        Book::PrayerOfManasseh,
+        Book::FirstEsdras,
+        Book::Ezra,
+        Book::Nehemiah,
+        Book::Tobit,
+        Book::Judith,
+        Book::Esther,
+        Book::FirstMaccabees,
+        Book::SecondMaccabees,
+        Book::ThirdMaccabees,
+        Book::Psalms,
+        Book::Psalm151,
+        Book::Job,
+        Book::Proverbs,
+        Book::Ecclesiastes,
+        Book::SongOfSongs,
+        Book::Wisdom,
+        Book::Sirach,
+        Book::Hosea,
+        Book::Amos,
+        Book::Micah,
+        Book::Joel,
+        Book::Obadiah,
+        Book::Jonah,
+        Book::Nahum,
+        Book::Habakkuk,
+        Book::Zephaniah,
+        Book::Haggai,
+        Book::Zechariah,
+        Book::Malachi,
+        Book::Isaiah,
+        Book::Jeremiah,
+        Book::Baruch,
+        Book::Lamentations,
+        Book::Ezekiel,
+        Book::Daniel,
+        Book::Matthew,
+        Book::Mark,
+        Book::Luke,
+        Book::John,
+        Book::Acts,
+        Book::Romans,
+        Book::FirstCorinthians,
+        Book::SecondCorinthians,
+        Book::Galatians,
+        Book::Ephesians,
+        Book::Philippians,
+        Book::Colossians,
+        Book::FirstThessalonians,
+        Book::SecondThessalonians,
+        Book::FirstTimothy,
+        Book::SecondTimothy,
+        Book::Titus,
+        Book::Philemon,
+        Book::Hebrews,
+        Book::James,
+        Book::FirstPeter,
+        Book::SecondPeter,
+        Book::FirstJohn,
+        Book::SecondJohn,
+        Book::ThirdJohn,
+        Book::Jude,
+        Book::Revelation,
+    ];
+}
+
+impl Canonical for OrthodoxCanon {
+    fn ordered_books(&self) -> &[Book] {
+        Self::ORDERED_BOOKS
     }
+}
 
-    fn book_at_position(&self, position: u8) -> Option<Book> {
-        self.ordered_books().get(position as usize).map(|b| *b)
+impl TanakhCanon {
+    const ORDERED_BOOKS: &'static [Book] = &[
+        Book::Genesis,
+        Book::Exodus,
+        Book::Leviticus,
+        Book::Numbers,
+        Book::Deuteronomy,
+        Book::Joshua,
+        Book::Judges,
+        Book::FirstSamuel,
+        Book::SecondSamuel,
+        Book::FirstKings,
+        Book::SecondKings,
+        Book::Isaiah,
+        Book::Jeremiah,
+        Book::Ezekiel,
+        Book::Hosea,
+        Book::Joel,
+        Book::Amos,
+        Book::Obadiah,
+        Book::Jonah,
+        Book::Micah,
+        Book::Nahum,
+        Book::Habakkuk,
+        Book::Zephaniah,
+        Book::Haggai,
+        Book::Zechariah,
+        Book::Malachi,
+        Book::Psalms,
+        Book::Proverbs,
+        Book::Job,
+        Book::SongOfSongs,
+        Book::Ruth,
+        Book::Lamentations,
+        Book::Ecclesiastes,
+        Book::Esther,
+        Book::Daniel,
+        Book::Ezra,
+        Book::Nehemiah,
+        Book::FirstChronicles,
+        Book::SecondChronicles,
+    ];
+}
+
+impl Canonical for TanakhCanon {
+    fn ordered_books(&self) -> &[Book] {
+        Self::ORDERED_BOOKS
     }
+}
 
-    // fn to_canon(&self) -> Canon {
-    //     Canon::Protestant
-    // }
+impl DynamicCanon {
+    pub fn new(books: Vec<Book>) -> Result<Self, String> {
+        if books.len() > u8::MAX as usize {
+            return Err(format!(
+                "a canon can hold at most {} books, not {}",
+                u8::MAX,
+                books.len()
+            ));
+        }
+        for (i, book) in books.iter().enumerate() {
+            if books[..i].contains(book) {
+                return Err(format!("{book} appears more than once in the canon"));
+            }
+        }
+        Ok(Self { books })
+    }
+
+    /// Copies the order of another canon, so it can be adjusted at runtime.
+    pub fn from_canon<C: Canonical + ?Sized>(canon: &C) -> Self {
+        Self {
+            books: canon.ordered_books().to_vec(),
+        }
+    }
+}
+
+impl Canonical for DynamicCanon {
+    fn ordered_books(&self) -> &[Book] {
+        &self.books
+    }
+}
+
+impl std::str::FromStr for DynamicCanon {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let books = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('#'))
+            .flat_map(|line| line.split(','))
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(Book::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(books)
+    }
 }
 
-pub(crate) struct InCanon<'c, T, C: Canonical> {
+pub struct InCanon<'c, T, C: Canonical + ?Sized> {
     pub(crate) inner: T,
     pub(crate) canon: &'c C,
 }
 
-impl<'c, T, C: Canonical> InCanon<'c, T, C> {
+impl<'c, T, C: Canonical + ?Sized> InCanon<'c, T, C> {
     pub fn new(inner: T, canon: &'c C) -> Self {
         Self { inner, canon }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scripture_span::ScriptureSpan;
+
+    #[test]
+    fn canons_contain_each_book_once() {
+        fn assert_unique<C: Canonical>(canon: C, expected_len: usize) {
+            let books = canon.ordered_books();
+            assert_eq!(books.len(), expected_len);
+            for (i, book) in books.iter().enumerate() {
+                assert_eq!(canon.book_position(*book), Some(i as u8), "{book}");
+            }
+        }
+        assert_unique(ProtestantCanon, 66);
+        assert_unique(CatholicCanon, 73);
+        assert_unique(OrthodoxCanon, 77);
+    }
+
+    #[test]
+    fn catholic_canon_places_deuterocanon_among_the_old_testament() {
+        let canon = CatholicCanon;
+        let position = |book| canon.book_position(book).unwrap();
+        assert_eq!(position(Book::Tobit), position(Book::Nehemiah) + 1);
+        assert_eq!(position(Book::Baruch), position(Book::Lamentations) + 1);
+        assert_eq!(position(Book::Matthew), 46);
+        assert_eq!(canon.book_position(Book::Psalm151), None);
+    }
+
+    #[test]
+    fn orthodox_canon_follows_the_septuagint_order() {
+        let canon = OrthodoxCanon;
+        let position = |book| canon.book_position(book).unwrap();
+        assert!(position(Book::Malachi) < position(Book::Isaiah));
+        assert_eq!(position(Book::Psalm151), position(Book::Psalms) + 1);
+        assert_eq!(position(Book::FirstEsdras), position(Book::Ezra) - 1);
+        assert_eq!(
+            canon.book_at_position(position(Book::Daniel) + 1),
+            Some(Book::Matthew)
+        );
+    }
+
+    #[test]
+    fn spans_use_the_position_within_the_canon() {
+        let catholic = CatholicCanon;
+        let tobit = InCanon::new(Book::Tobit, &catholic);
+        let judith = InCanon::new(Book::Judith, &catholic);
+        assert_eq!(
+            tobit.end_position().unwrap().get(),
+            judith.start_position().unwrap().get()
+        );
+        let orthodox = OrthodoxCanon;
+        assert_ne!(
+            tobit.start_position(),
+            InCanon::new(Book::Tobit, &orthodox).start_position()
+        );
+
+        let protestant = ProtestantCanon;
+        assert!(
+            InCanon::new(Book::Tobit, &protestant)
+                .start_position()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn tanakh_canon_ends_with_chronicles() {
+        let canon = TanakhCanon;
+        assert_eq!(canon.ordered_books().len(), 39);
+        assert_eq!(canon.book_at_position(38), Some(Book::SecondChronicles));
+        assert_eq!(
+            canon.compare_books(Book::Daniel, Book::Malachi),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(canon.compare_books(Book::Genesis, Book::Matthew), None);
+    }
+
+    #[test]
+    fn books_sort_in_canon_order() {
+        let mut books = vec![
+            Book::Psalms,
+            Book::SecondChronicles,
+            Book::Malachi,
+            Book::Ruth,
+        ];
+        let tanakh = TanakhCanon;
+        books.sort_by_key(|b| tanakh.book_position(*b));
+        assert_eq!(
+            books,
+            vec![
+                Book::Malachi,
+                Book::Psalms,
+                Book::Ruth,
+                Book::SecondChronicles
+            ]
+        );
+
+        let protestant = ProtestantCanon;
+        books.sort_by_key(|b| protestant.book_position(*b));
+        assert_eq!(
+            books,
+            vec![
+                Book::Ruth,
+                Book::SecondChronicles,
+                Book::Psalms,
+                Book::Malachi
+            ]
+        );
+    }
+
+    #[test]
+    fn ranges_across_books_follow_the_canon() {
+        // "Malachi – Psalms" runs forwards in the Tanakh, but backwards in the protestant canon.
+        let tanakh = TanakhCanon;
+        let malachi = InCanon::new(Book::Malachi, &tanakh)
+            .start_position()
+            .unwrap();
+        let psalms = InCanon::new(Book::Psalms, &tanakh)
+            .start_position()
+            .unwrap();
+        assert!(malachi < psalms);
+
+        let protestant = ProtestantCanon;
+        let malachi = InCanon::new(Book::Malachi, &protestant)
+            .start_position()
+            .unwrap();
+        let psalms = InCanon::new(Book::Psalms, &protestant)
+            .start_position()
+            .unwrap();
+        assert!(malachi > psalms);
+    }
+
+    #[test]
+    fn dynamic_canons_reject_duplicate_books() {
+        assert!(DynamicCanon::new(vec![Book::Genesis, Book::Exodus]).is_ok());
+        assert!(DynamicCanon::new(vec![Book::Genesis, Book::Exodus, Book::Genesis]).is_err());
+    }
+
+    #[test]
+    fn dynamic_canons_parse_from_configuration() {
+        let config = "# Sunday readings\nIsaiah\nPsalms, Romans\n\nMatthew\n";
+        let canon: DynamicCanon = config.parse().unwrap();
+        assert_eq!(
+            canon.ordered_books(),
+            &[Book::Isaiah, Book::Psalms, Book::Romans, Book::Matthew]
+        );
+        assert!("Genesis, Hezekiah".parse::<DynamicCanon>().is_err());
+    }
+
+    #[test]
+    fn dynamic_canons_can_copy_a_static_canon() {
+        let canon = DynamicCanon::from_canon(&TanakhCanon);
+        assert_eq!(canon.ordered_books(), TanakhCanon.ordered_books());
+    }
+
+    #[test]
+    fn canons_can_be_used_as_trait_objects() {
+        let lectionary: DynamicCanon = "Isaiah, Matthew".parse().unwrap();
+        let canons: [&dyn Canonical; 2] = [&ProtestantCanon, &lectionary];
+        let positions: Vec<_> = canons
+            .iter()
+            .map(|canon| {
+                InCanon::new(Book::Matthew, *canon)
+                    .start_position()
+                    .unwrap()
+                    .get()
+            })
+            .collect();
+        assert_eq!(positions, vec![39 << 24, 1 << 24]);
+
+        let verse = crate::bvc::Verse::new(
+            Book::Isaiah,
+            crate::bvc::ChapterNumber::new(66).unwrap(),
+            crate::bvc::VerseNumber::new(24).unwrap(),
+        )
+        .unwrap();
+        let span = InCanon::new(verse, &lectionary as &dyn Canonical);
+        assert_eq!(
+            span.end_position().unwrap().get(),
+            InCanon::new(Book::Matthew, &lectionary as &dyn Canonical)
+                .start_position()
+                .unwrap()
+                .get()
+        );
+    }
+}
@@ -1,11 +1,62 @@
 #![allow(unused)]
 
-use crate::bvc::BookSeries;
+use crate::bvc::{Book, BookSeries, VersePartLabel};
 
 pub mod token;
 
 use std::str::FromStr;
-pub use token::Token;
+pub use token::{SpannedToken, Token};
+
+/// The most words a book name can have, e.g. "Cantar de los Cantares".
+const MAX_BOOK_NAME_WORDS: usize = 5;
+
+/// Words written in front of the name of a numbered book, e.g. "II" in "II Corinthians".
+const ORDINAL_WORDS: &[(&str, u8)] = &[
+    ("i", 1),
+    ("ii", 2),
+    ("iii", 3),
+    ("first", 1),
+    ("second", 2),
+    ("third", 3),
+];
+
+/// Characters that join the ends of a range, e.g. the en dash in "John 3:16–18". References copied
+/// from published text rarely use the ASCII hyphen.
+const DASHES: &[char] = &[
+    '-',        // hyphen-minus
+    '\u{2010}', // hyphen
+    '\u{2011}', // non-breaking hyphen
+    '\u{2012}', // figure dash
+    '\u{2013}', // en dash
+    '\u{2014}', // em dash
+    '\u{2015}', // horizontal bar
+    '\u{2212}', // minus sign
+    '\u{FE58}', // small em dash
+    '\u{FE63}', // small hyphen-minus
+    '\u{FF0D}', // fullwidth hyphen-minus
+];
+
+/// Words written in front of numbers to say what they are, e.g. "vv" in "vv. 4-6", and after a
+/// verse to add the verses after it, e.g. "ff" in "John 3:16ff".
+const MARKER_WORDS: &[(&str, Token)] = &[
+    ("f", Token::F),
+    ("ff", Token::FF),
+    ("v", Token::Verse),
+    ("vv", Token::Verse),
+    ("vs", Token::Verse),
+    ("vss", Token::Verse),
+    ("ver", Token::Verse),
+    ("verse", Token::Verse),
+    ("verses", Token::Verse),
+    ("ch", Token::Chapter),
+    ("chs", Token::Chapter),
+    ("chap", Token::Chapter),
+    ("chapter", Token::Chapter),
+    ("chapters", Token::Chapter),
+];
+
+/// Suffixes written after the number of a numbered book, e.g. "st" in "1st John".
+const ORDINAL_SUFFIXES: &[&str] = &["st", "nd", "rd"];
 
 #[derive(thiserror::Error, miette::Diagnostic, Debug)]
 #[error("Unexpected token '{token}'")]
@@ -23,7 +74,7 @@ pub(crate) struct Lexer<'de> {
     original: &'de str,
     rest: &'de str,
     current_byte: usize,
-    peeked: Option<Result<Token, miette::Error>>,
+    peeked: Option<Result<SpannedToken, miette::Error>>,
 }
 
 impl<'de> Lexer<'de> {
@@ -36,7 +87,7 @@ impl<'de> Lexer<'de> {
         }
     }
 
-    pub fn peek(&mut self) -> Option<&Result<Token, miette::Error>> {
+    pub fn peek(&mut self) -> Option<&Result<SpannedToken, miette::Error>> {
         if self.peeked.is_some() {
             return self.peeked.as_ref();
         }
@@ -45,8 +96,154 @@ impl<'de> Lexer<'de> {
     }
 }
 
+/// Returns the end of each of the first words of the input, where words are runs of alphabetic
+/// characters separated by whitespace.
+fn word_ends(input: &str, max_words: usize) -> Vec<usize> {
+    let mut ends = Vec::new();
+    let mut offset = 0;
+    while ends.len() < max_words {
+        let rest = &input[offset..];
+        let word_start = rest.len() - rest.trim_start().len();
+        if !ends.is_empty() && word_start == 0 {
+            break;
+        }
+        let word = &rest[word_start..];
+        let word_len = word
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(word.len());
+        if word_len == 0 {
+            break;
+        }
+        offset += word_start + word_len;
+        ends.push(offset);
+    }
+    ends
+}
+
+/// Returns the number in front of the name of a numbered book and the bytes it takes up,
+/// including the whitespace after it, e.g. 2 and 4 for "2nd Kings".
+fn ordinal_prefix(input: &str) -> Option<(u8, usize)> {
+    let digits = input.len() - input.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let word = &input[..input
+        .find(|c: char| !c.is_alphanumeric())
+        .unwrap_or(input.len())];
+    let (number, len) = if digits > 0 {
+        let number = input[..digits].parse().ok()?;
+        // the number may be written right before the name, as in "1Kings"
+        let suffix = &word[digits..];
+        let is_suffix = ORDINAL_SUFFIXES
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(suffix));
+        (number, if is_suffix { word.len() } else { digits })
+    } else {
+        let (_, number) = ORDINAL_WORDS
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(word))?;
+        if !input[word.len()..].starts_with(char::is_whitespace) {
+            return None;
+        }
+        (*number, word.len())
+    };
+    let rest = &input[len..];
+    Some((number, input.len() - rest.trim_start().len()))
+}
+
+/// Returns the token for a marker word, e.g. `Token::FF` for "ff".
+fn marker(word: &str) -> Option<Token> {
+    MARKER_WORDS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(word))
+        .map(|(_, token)| *token)
+}
+
+/// Returns the series of the longest book name at the start of the input and the bytes it takes
+/// up, including the period that ends an abbreviation, e.g. "Gen.".
+fn series_name(input: &str) -> Option<(BookSeries, usize)> {
+    let (series, end) = word_ends(input, MAX_BOOK_NAME_WORDS)
+        .into_iter()
+        .rev()
+        .find_map(|end| Some((BookSeries::from_str(&input[..end]).ok()?, end)))?;
+    // periods only separate numbers, so a period right after a name belongs to the name
+    let end = if input[end..].starts_with('.') {
+        end + 1
+    } else {
+        end
+    };
+    Some((series, end))
+}
+
+/// Returns the book whose name ends with a number at the start of the input and the bytes it
+/// takes up, e.g. `Book::Psalm151` for "Psalm 151", which would otherwise be read as chapter 151
+/// of Psalms.
+fn book_numbered_after(input: &str) -> Option<(Book, usize)> {
+    word_ends(input, MAX_BOOK_NAME_WORDS)
+        .into_iter()
+        .rev()
+        .find_map(|end| {
+            let rest = &input[end..];
+            let number = rest.trim_start();
+            let digits = number.len()
+                - number
+                    .trim_start_matches(|c: char| c.is_ascii_digit())
+                    .len();
+            if digits == 0 {
+                return None;
+            }
+            let end = input.len() - number.len() + digits;
+            Some((Book::try_from(&input[..end]).ok()?, end))
+        })
+}
+
+/// Returns the numbered book at the start of the input and the bytes it takes up, e.g.
+/// `Book::SecondCorinthians` for "II Cor".
+fn numbered_book(input: &str) -> Option<(Book, usize)> {
+    let (number, prefix) = ordinal_prefix(input)?;
+    let (series, len) = series_name(&input[prefix..])?;
+    Some((Book::from_series(Some(number), series)?, prefix + len))
+}
+
+/// Returns the book at the start of the input and the bytes it takes up, like the lexer reads it
+/// but without building an error when there is none, e.g. to look for books in free text.
+pub(crate) fn book_at(input: &str) -> Option<(Book, usize)> {
+    if let Some(book) = numbered_book(input) {
+        return Some(book);
+    }
+    let first_word = &input[..word_ends(input, 1).first().copied()?];
+    if marker(first_word).is_some() {
+        return None;
+    }
+    if let Some(book) = book_numbered_after(input) {
+        return Some(book);
+    }
+    let (series, end) = series_name(input)?;
+    Some((Book::from_series(None, series)?, end))
+}
+
+/// Returns whether the lexer reads a token rather than an error at the start of the input, where
+/// the input follows a number if `after_number`, without building the error, e.g. to stop at the
+/// end of a reference in free text.
+pub(crate) fn starts_with_token(input: &str, after_number: bool) -> bool {
+    let rest = input.trim_start();
+    let Some(c) = rest.chars().next() else {
+        return false;
+    };
+    match c {
+        ':' | ',' | ';' | '.' | '0'..='9' => true,
+        c if DASHES.contains(&c) => true,
+        c if c.is_alphabetic() => {
+            let first_word = &rest[..word_ends(rest, 1)[0]];
+            let verse_part = after_number
+                && rest.len() == input.len()
+                && first_word.len() == c.len_utf8()
+                && VersePartLabel::try_from(c).is_ok();
+            verse_part || marker(first_word).is_some() || book_at(rest).is_some()
+        }
+        _ => false,
+    }
+}
+
 impl<'de> Iterator for Lexer<'de> {
-    type Item = Result<Token, miette::Error>;
+    type Item = Result<SpannedToken, miette::Error>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(next) = self.peeked.take() {
@@ -73,67 +270,107 @@ impl<'de> Iterator for Lexer<'de> {
                 Number,
             }
 
+            let start_byte = self.current_byte - c.len_utf8();
+            let just = |token| {
+                Some(Ok(SpannedToken {
+                    token,
+                    span: (start_byte, c.len_utf8()).into(),
+                }))
+            };
+
             let lead = match c {
-                ':' => return Some(Ok(Token::Colon)),
-                ',' => return Some(Ok(Token::Comma)),
-                '-' => return Some(Ok(Token::Dash)),
-                ';' => return Some(Ok(Token::SemiColon)),
+                ':' => return just(Token::Colon),
+                ',' => return just(Token::Comma),
+                c if DASHES.contains(&c) => return just(Token::Dash),
+                ';' => return just(Token::SemiColon),
+                '.' => return just(Token::Period),
                 '0'..='9' => LeadToken::Number,
-                'a'..='z' | 'A'..='Z' => LeadToken::Identifer,
+                c if c.is_alphabetic() => LeadToken::Identifer,
                 c if c.is_whitespace() => unreachable!("handled before match"),
                 c => {
-                    let c_pos = self.current_byte - c.len_utf8();
                     return Some(Err(UnexpectedToken {
                         src: self.original.to_string(),
                         token: c,
-                        err_span: (c_pos, c.len_utf8()).into(),
+                        err_span: (start_byte, c.len_utf8()).into(),
                     }
                     .into()));
                 }
             };
 
-            break match lead {
-                // book, ff, or subverse
+            let token = match lead {
+                // book, marker, or subverse
                 LeadToken::Identifer => {
-                    let first_non_idenifier = c_onwards
-                        .find(|c| !matches!(c, 'a'..='z' | 'A'..='Z'))
-                        .unwrap_or_else(|| c_onwards.len());
-                    let literal = &c_onwards[..first_non_idenifier];
-                    let bytes_from_chars = literal.len() - c.len_utf8() + 1;
-                    self.rest = &c_onwards[bytes_from_chars..];
-
-                    let token = match literal {
-                        "ff" => Ok(Token::FF),
-                        // if 'a'..'z' => Ok(TokenKind::Subverse),
-                        l => match crate::bvc::BookSeries::from_str(l) {
-                            Ok(book) => Ok(Token::Book(book)),
-                            Err(e) => {
-                                Err(miette::miette! {
-                                    labels = vec![miette::LabeledSpan::at(self.current_byte-1..self.current_byte + bytes_from_chars-1, "these literal characters")],
-                                    "{e}"
-                                }.with_source_code(self.original.to_string()))
-                            }
-                        },
+                    let first_word = &c_onwards[..word_ends(c_onwards, 1)[0]];
+                    // a letter right after a verse number is a part of the verse, e.g. "16a"
+                    let after_number =
+                        self.original[..start_byte].ends_with(|c: char| c.is_ascii_digit());
+                    let verse_part = VersePartLabel::try_from(c)
+                        .ok()
+                        .filter(|_| after_number && first_word.len() == c.len_utf8());
+                    let (end_of_literal, token) = if let Some(part) = verse_part {
+                        (first_word.len(), Ok(Token::Subverse(part)))
+                    } else if let Some((book, end)) = numbered_book(c_onwards) {
+                        (end, Ok(Token::Book(book)))
+                    } else if let Some(token) = marker(first_word) {
+                        // "ff." and "vv." are written with a period, like abbreviations
+                        let rest = &c_onwards[first_word.len()..];
+                        (first_word.len() + rest.starts_with('.') as usize, Ok(token))
+                    } else if let Some((book, end)) = book_numbered_after(c_onwards) {
+                        (end, Ok(Token::Book(book)))
+                    } else if let Some((series, end)) = series_name(c_onwards) {
+                        // numbered books need their number, e.g. "Kings" on its own is no book
+                        let book = Book::from_series(None, series)
+                            .map(Token::Book)
+                            .ok_or_else(|| format!("not a valid book: {}", &c_onwards[..end]));
+                        (end, book)
+                    } else {
+                        (
+                            first_word.len(),
+                            Err(format!("not a valid book: {first_word}")),
+                        )
                     };
+                    self.rest = &c_onwards[end_of_literal..];
+                    self.current_byte = start_byte + end_of_literal;
 
-                    self.current_byte += bytes_from_chars - 1;
-
-                    Some(token)
+                    match token {
+                        Ok(token) => token,
+                        Err(e) => {
+                            return Some(Err(miette::miette! {
+                                labels = vec![miette::LabeledSpan::at(start_byte..self.current_byte, "these literal characters")],
+                                "{e}"
+                            }.with_source_code(self.original.to_string())));
+                        }
+                    }
                 }
                 LeadToken::Number => {
-                    let first_non_digit = c_onwards
-                        .find(|c| !matches!(c, '0'..='9'))
-                        .unwrap_or_else(|| c_onwards.len());
-                    let digits = &c_onwards[..first_non_digit];
-                    let bytes_from_digits = digits.len() - c.len_utf8() + 1;
-                    self.rest = &c_onwards[bytes_from_digits..];
-                    let n = match digits.parse() {
-                        Ok(n) => n,
-                        Err(_) => todo!(),
-                    };
-                    return Some(Ok(Token::Number(n)));
+                    if let Some((book, end)) = numbered_book(c_onwards) {
+                        self.rest = &c_onwards[end..];
+                        self.current_byte = start_byte + end;
+                        Token::Book(book)
+                    } else {
+                        let first_non_digit = c_onwards
+                            .find(|c: char| !c.is_ascii_digit())
+                            .unwrap_or(c_onwards.len());
+                        let digits = &c_onwards[..first_non_digit];
+                        self.rest = &c_onwards[first_non_digit..];
+                        self.current_byte = start_byte + first_non_digit;
+                        match digits.parse() {
+                            Ok(n) => Token::Number(n),
+                            Err(_) => {
+                                return Some(Err(miette::miette! {
+                                    labels = vec![miette::LabeledSpan::at(start_byte..self.current_byte, "this number")],
+                                    "{digits} is too large to be a chapter or verse"
+                                }.with_source_code(self.original.to_string())));
+                            }
+                        }
+                    }
                 }
             };
+
+            break Some(Ok(SpannedToken {
+                token,
+                span: (start_byte, self.current_byte - start_byte).into(),
+            }));
         }
     }
 }
@@ -141,7 +378,6 @@ impl<'de> Iterator for Lexer<'de> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::bvc::BookSeries;
 
     #[test]
     fn lex_simple_characters() {
@@ -153,17 +389,75 @@ mod test {
             (Token::SemiColon, ""),
         ];
         for expected in expected_tokens {
-            let token = lexer.next().unwrap().unwrap();
+            let token = lexer.next().unwrap().unwrap().token;
             let (expected, rest) = expected;
             assert_eq!(token, expected);
             assert_eq!(lexer.rest, rest);
         }
     }
 
+    #[test]
+    fn lex_unicode_dashes() {
+        for dash in DASHES {
+            let input = format!("4{dash}7");
+            let mut lexer = Lexer::new(&input);
+            assert_eq!(lexer.next().unwrap().unwrap().token, Token::Number(4));
+            let token = lexer.next().unwrap().unwrap();
+            assert_eq!(token.token, Token::Dash, "{dash:?}");
+            assert_eq!(token.span, (1, dash.len_utf8()).into());
+            assert_eq!(lexer.next().unwrap().unwrap().token, Token::Number(7));
+        }
+    }
+
+    #[test]
+    fn lex_unicode_whitespace() {
+        // no-break, thin, narrow no-break, ideographic and em spaces, as found in PDFs
+        let mut lexer = Lexer::new("Song\u{a0}of\u{2009}Songs\u{202f}2\u{3000}:\u{2003}1");
+        let expected_tokens = vec![
+            Token::Book(Book::SongOfSongs),
+            Token::Number(2),
+            Token::Colon,
+            Token::Number(1),
+        ];
+        for expected in expected_tokens {
+            assert_eq!(lexer.next().unwrap().unwrap().token, expected);
+        }
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    fn lex_copied_references() {
+        let test_cases = [
+            // typeset with an en dash and a non-breaking space
+            "1\u{a0}Cor. 13:4\u{2013}7",
+            // from a web page, with a thin space around an em dash
+            "1 Cor 13:4\u{2009}\u{2014}\u{2009}7",
+            // line broken in a PDF
+            "1 Cor.\r\n13:4\u{2011}7",
+        ];
+        for input in test_cases {
+            let tokens: Vec<Token> = Lexer::new(input)
+                .map(|token| token.unwrap().token)
+                .collect();
+            assert_eq!(
+                tokens,
+                vec![
+                    Token::Book(Book::FirstCorinthians),
+                    Token::Number(13),
+                    Token::Colon,
+                    Token::Number(4),
+                    Token::Dash,
+                    Token::Number(7),
+                ],
+                "{input:?}"
+            );
+        }
+    }
+
     #[test]
     fn lex_a_single_digit_number() {
         let mut lexer = Lexer::new("1");
-        let token = lexer.next().unwrap().unwrap();
+        let token = lexer.next().unwrap().unwrap().token;
         assert_eq!(token, Token::Number(1));
         assert_eq!(lexer.rest, "");
     }
@@ -171,7 +465,7 @@ mod test {
     #[test]
     fn lex_a_multiple_digit_number() {
         let mut lexer = Lexer::new("123");
-        assert_eq!(lexer.next().unwrap().unwrap(), Token::Number(123));
+        assert_eq!(lexer.next().unwrap().unwrap().token, Token::Number(123));
         assert_eq!(lexer.rest, "");
     }
 
@@ -180,7 +474,7 @@ mod test {
         let mut lexer = Lexer::new("1 123");
         let expected_tokens = vec![(1, " 123"), (123, "")];
         for expected in expected_tokens {
-            let token = lexer.next().unwrap().unwrap();
+            let token = lexer.next().unwrap().unwrap().token;
             assert_eq!(token, Token::Number(expected.0));
             assert_eq!(lexer.rest, expected.1);
         }
@@ -189,38 +483,176 @@ mod test {
     #[test]
     fn lex_books() {
         let mut lexer = Lexer::new("Psalms");
-        let expected_tokens = vec![(Token::Book(BookSeries::Psalms), "")];
+        let expected_tokens = vec![(Token::Book(Book::Psalms), "")];
         for expected in expected_tokens {
-            let token = lexer.next().unwrap().unwrap();
+            let token = lexer.next().unwrap().unwrap().token;
             let (expected_token, rest) = expected;
             assert_eq!(token, expected_token);
             assert_eq!(lexer.rest, rest);
         }
     }
 
+    #[test]
+    fn lex_books_ignoring_case() {
+        for input in ["psalms", "PSALMS", "pSaLmS"] {
+            let mut lexer = Lexer::new(input);
+            assert_eq!(
+                lexer.next().unwrap().unwrap().token,
+                Token::Book(Book::Psalms)
+            );
+        }
+    }
+
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn lex_books_with_accents() {
+        let mut lexer = Lexer::new("Éxodo 3");
+        assert_eq!(
+            lexer.next().unwrap().unwrap().token,
+            Token::Book(Book::Exodus)
+        );
+        assert_eq!(lexer.rest, " 3");
+    }
+
+    #[test]
+    fn lex_multiple_word_books() {
+        let mut lexer = Lexer::new("Song of Songs 2:1");
+        let token = lexer.next().unwrap().unwrap();
+        assert_eq!(token.token, Token::Book(Book::SongOfSongs));
+        assert_eq!(token.span, (0, 13).into());
+        assert_eq!(lexer.rest, " 2:1");
+    }
+
+    #[test]
+    fn lex_the_longest_book_name() {
+        // "Song" is also a name of the Song of Songs
+        let mut lexer = Lexer::new("song  of\tsolomon");
+        let token = lexer.next().unwrap().unwrap();
+        assert_eq!(token.token, Token::Book(Book::SongOfSongs));
+        assert_eq!(token.span, (0, 16).into());
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    fn lex_abbreviations_with_periods() {
+        let test_cases = [
+            ("Gen. 1:1", Book::Genesis, 4),
+            ("Matt.", Book::Matthew, 5),
+            ("1 Cor. 13:4-7", Book::FirstCorinthians, 6),
+            ("Song of Sol. 2", Book::SongOfSongs, 12),
+        ];
+        for (input, book, len) in test_cases {
+            let mut lexer = Lexer::new(input);
+            let token = lexer.next().unwrap().unwrap();
+            assert_eq!(token.token, Token::Book(book), "{input}");
+            assert_eq!(token.span, (0, len).into(), "{input}");
+        }
+    }
+
+    #[test]
+    fn lex_periods_between_numbers() {
+        let mut lexer = Lexer::new("Gen 1.1");
+        let expected_tokens = vec![
+            (Token::Book(Book::Genesis), " 1.1"),
+            (Token::Number(1), ".1"),
+            (Token::Period, "1"),
+            (Token::Number(1), ""),
+        ];
+        for (expected_token, rest) in expected_tokens {
+            assert_eq!(lexer.next().unwrap().unwrap().token, expected_token);
+            assert_eq!(lexer.rest, rest);
+        }
+    }
+
+    #[test]
+    fn lex_books_followed_by_other_words() {
+        let mut lexer = Lexer::new("John and Acts");
+        assert_eq!(
+            lexer.next().unwrap().unwrap().token,
+            Token::Book(Book::John)
+        );
+        assert_eq!(lexer.rest, " and Acts");
+    }
+
+    #[test]
+    fn lex_unknown_books() {
+        let mut lexer = Lexer::new("Hezekiah");
+        assert!(lexer.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn lex_numbers_too_large_for_a_verse() {
+        let mut lexer = Lexer::new("John 3:1600");
+        lexer.next();
+        lexer.next();
+        lexer.next();
+        let error = lexer.next().unwrap().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "1600 is too large to be a chapter or verse"
+        );
+        assert!(
+            error
+                .labels()
+                .is_some_and(|mut labels| labels.next().is_some())
+        );
+    }
+
     #[test]
     fn lex_simple_reference() {
         let mut lexer = Lexer::new("Psalms 1:10");
         let expected_tokens = vec![
-            (Token::Book(BookSeries::Psalms), " 1:10"),
+            (Token::Book(Book::Psalms), " 1:10"),
             (Token::Number(1), ":10"),
             (Token::Colon, "10"),
             (Token::Number(10), ""),
         ];
         for expected in expected_tokens {
-            let token = lexer.next().unwrap().unwrap();
+            let token = lexer.next().unwrap().unwrap().token;
             let (expected_token, rest) = expected;
             assert_eq!(token, expected_token);
             assert_eq!(lexer.rest, rest);
         }
     }
 
+    #[test]
+    fn lex_verse_parts() {
+        let mut lexer = Lexer::new("Ps 23:1a,3b");
+        let expected_tokens = vec![
+            Token::Book(Book::Psalms),
+            Token::Number(23),
+            Token::Colon,
+            Token::Number(1),
+            Token::Subverse(VersePartLabel::new(b'a').unwrap()),
+            Token::Comma,
+            Token::Number(3),
+            Token::Subverse(VersePartLabel::new(b'b').unwrap()),
+        ];
+        for expected in expected_tokens {
+            assert_eq!(lexer.next().unwrap().unwrap().token, expected);
+        }
+        assert!(lexer.next().is_none());
+    }
+
+    #[test]
+    fn lex_books_starting_with_verse_part_letters() {
+        let test_cases = [
+            ("3 Am 1", Token::Book(Book::Amos)),
+            ("3 Dan 1", Token::Book(Book::Daniel)),
+        ];
+        for (input, expected) in test_cases {
+            let mut lexer = Lexer::new(input);
+            assert_eq!(lexer.next().unwrap().unwrap().token, Token::Number(3));
+            assert_eq!(lexer.next().unwrap().unwrap().token, expected, "{input}");
+        }
+    }
+
     #[test]
     fn lex_following_verses() {
         let mut lexer = Lexer::new("ff");
         let expected_tokens = vec![(Token::FF, "")];
         for expected in expected_tokens {
-            let token = lexer.next().unwrap().unwrap();
+            let token = lexer.next().unwrap().unwrap().token;
             let (expected_token, rest) = expected;
             assert_eq!(token, expected_token);
             assert_eq!(lexer.rest, rest);
@@ -228,17 +660,174 @@ mod test {
     }
 
     #[test]
-    fn lex_books_with_number() {
-        let mut lexer = Lexer::new("1 Kings");
+    fn lex_chapter_and_verse_markers() {
+        let mut lexer = Lexer::new("v. 7; vv 4-6; Ch. 5; verses 1");
         let expected_tokens = vec![
-            (Token::Number(1), " Kings"),
-            (Token::Book(BookSeries::Kings), ""),
+            (Token::Verse, " 7; vv 4-6; Ch. 5; verses 1"),
+            (Token::Number(7), "; vv 4-6; Ch. 5; verses 1"),
+            (Token::SemiColon, " vv 4-6; Ch. 5; verses 1"),
+            (Token::Verse, " 4-6; Ch. 5; verses 1"),
+            (Token::Number(4), "-6; Ch. 5; verses 1"),
+            (Token::Dash, "6; Ch. 5; verses 1"),
+            (Token::Number(6), "; Ch. 5; verses 1"),
+            (Token::SemiColon, " Ch. 5; verses 1"),
+            (Token::Chapter, " 5; verses 1"),
+            (Token::Number(5), "; verses 1"),
+            (Token::SemiColon, " verses 1"),
+            (Token::Verse, " 1"),
+            (Token::Number(1), ""),
         ];
-        for expected in expected_tokens {
-            let token = lexer.next().unwrap().unwrap();
-            let (expected_token, rest) = expected;
-            assert_eq!(token, expected_token);
+        for (expected_token, rest) in expected_tokens {
+            assert_eq!(lexer.next().unwrap().unwrap().token, expected_token);
+            assert_eq!(lexer.rest, rest);
+        }
+    }
+
+    #[test]
+    fn lex_the_next_verse() {
+        let mut lexer = Lexer::new("16f; 8ff.");
+        let expected_tokens = vec![
+            (Token::Number(16), "f; 8ff."),
+            (Token::F, "; 8ff."),
+            (Token::SemiColon, " 8ff."),
+            (Token::Number(8), "ff."),
+            (Token::FF, ""),
+        ];
+        for (expected_token, rest) in expected_tokens {
+            assert_eq!(lexer.next().unwrap().unwrap().token, expected_token);
             assert_eq!(lexer.rest, rest);
         }
     }
+
+    #[test]
+    fn lex_books_with_number() {
+        let mut lexer = Lexer::new("1 Kings 2");
+        let token = lexer.next().unwrap().unwrap();
+        assert_eq!(token.token, Token::Book(Book::FirstKings));
+        assert_eq!(token.span, (0, 7).into());
+        assert_eq!(lexer.rest, " 2");
+    }
+
+    #[test]
+    fn lex_books_with_number_prefixes_in_every_style() {
+        let test_cases = [
+            ("1Kings", Book::FirstKings),
+            ("1Kgs", Book::FirstKings),
+            ("I Kings", Book::FirstKings),
+            ("II Cor", Book::SecondCorinthians),
+            ("iii john", Book::ThirdJohn),
+            ("First Corinthians", Book::FirstCorinthians),
+            ("1st John", Book::FirstJohn),
+            ("2ND Timothy", Book::SecondTimothy),
+            ("Third John", Book::ThirdJohn),
+        ];
+        for (input, book) in test_cases {
+            let mut lexer = Lexer::new(input);
+            let token = lexer.next().unwrap().unwrap();
+            assert_eq!(token.token, Token::Book(book), "{input}");
+            assert_eq!(token.span, (0, input.len()).into(), "{input}");
+        }
+    }
+
+    #[test]
+    fn lex_numbered_books_followed_by_a_verse() {
+        let test_cases = [
+            ("III John 4", Book::ThirdJohn, vec![Token::Number(4)]),
+            ("Third John 4", Book::ThirdJohn, vec![Token::Number(4)]),
+            (
+                "1st Cor 13:4",
+                Book::FirstCorinthians,
+                vec![Token::Number(13), Token::Colon, Token::Number(4)],
+            ),
+            (
+                "2nd Kings 2:11",
+                Book::SecondKings,
+                vec![Token::Number(2), Token::Colon, Token::Number(11)],
+            ),
+        ];
+        for (input, book, rest) in test_cases {
+            let tokens: Vec<Token> = Lexer::new(input)
+                .map(|token| token.unwrap().token)
+                .collect();
+            assert_eq!(tokens[0], Token::Book(book), "{input}");
+            assert_eq!(tokens[1..], rest, "{input}");
+        }
+    }
+
+    #[test]
+    fn lex_numbers_before_other_books() {
+        let mut lexer = Lexer::new("John 3 Acts");
+        assert_eq!(
+            lexer.next().unwrap().unwrap().token,
+            Token::Book(Book::John)
+        );
+        assert_eq!(lexer.next().unwrap().unwrap().token, Token::Number(3));
+        assert_eq!(
+            lexer.next().unwrap().unwrap().token,
+            Token::Book(Book::Acts)
+        );
+    }
+
+    #[test]
+    fn lex_numbered_books_without_their_number() {
+        let mut lexer = Lexer::new("Kings");
+        assert!(lexer.next().unwrap().is_err());
+        let mut lexer = Lexer::new("4 Kings");
+        assert_eq!(lexer.next().unwrap().unwrap().token, Token::Number(4));
+        assert!(lexer.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn lex_books_whose_name_ends_with_a_number() {
+        let test_cases = [
+            ("Psalm 151", Token::Book(Book::Psalm151), None),
+            (
+                "Ps 151 4",
+                Token::Book(Book::Psalm151),
+                Some(Token::Number(4)),
+            ),
+            (
+                "Psalm 150",
+                Token::Book(Book::Psalms),
+                Some(Token::Number(150)),
+            ),
+            (
+                "Psalms 15",
+                Token::Book(Book::Psalms),
+                Some(Token::Number(15)),
+            ),
+            ("Psalm 1511", Token::Book(Book::Psalms), None),
+        ];
+        for (input, book, next) in test_cases {
+            let mut lexer = Lexer::new(input);
+            assert_eq!(lexer.next().unwrap().unwrap().token, book, "{input}");
+            assert_eq!(
+                lexer.next().and_then(Result::ok).map(|t| t.token),
+                next,
+                "{input}"
+            );
+        }
+    }
+
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn lex_books_whose_spanish_name_ends_with_a_number() {
+        let mut lexer = Lexer::new("Salmo 151");
+        assert_eq!(
+            lexer.next().unwrap().unwrap().token,
+            Token::Book(Book::Psalm151)
+        );
+        assert!(lexer.next().is_none());
+    }
+
+    #[cfg(feature = "lang-es")]
+    #[test]
+    fn lex_numbered_books_whose_series_shares_a_name() {
+        // Ezra is "Esdras" in Spanish, but 3 Esdras is still 1 Esdras
+        let mut lexer = Lexer::new("3 Esdras");
+        assert_eq!(
+            lexer.next().unwrap().unwrap().token,
+            Token::Book(Book::FirstEsdras)
+        );
+    }
 }
@@ -2,31 +2,43 @@ use std::fmt;
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Token {
-    Book(crate::bvc::BookSeries),
+    Book(crate::bvc::Book),
+    Chapter, // "ch." in front of chapters
     Colon,
     Comma,
     Dash,
     // https://www.chicagomanualofstyle.org/qanda/data/faq/topics/Documentation/faq0361.html
-    // F, // next
+    F,  // next
     FF, // all following
     Number(u8),
     Period,
     SemiColon,
-    Subverse,
+    Subverse(crate::bvc::VersePartLabel),
+    Verse, // "v." or "vv." in front of verses
+}
+
+/// A token and the bytes of the input it was lexed from.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SpannedToken {
+    pub token: Token,
+    pub span: miette::SourceSpan,
 }
 
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Token::Book(b) => write!(f, "BOOK {b}"),
+            Token::Chapter => write!(f, "CHAPTER null"),
             Token::Comma => write!(f, "COMMA null"),
             Token::Colon => write!(f, "COLON null"),
             Token::Dash => write!(f, "DASH null"),
+            Token::F => write!(f, "F null"),
             Token::FF => write!(f, "FF null"),
             Token::Number(n) => write!(f, "NUMBER {n}"),
             Token::Period => write!(f, "PERIOD null"),
             Token::SemiColon => write!(f, "SEMICOLON null"),
-            Token::Subverse => write!(f, "SUBVERSE null"),
+            Token::Subverse(part) => write!(f, "SUBVERSE {part}"),
+            Token::Verse => write!(f, "VERSE null"),
         }
     }
 }
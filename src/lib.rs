@@ -1,12 +1,149 @@
+use std::ops::Range;
+
+pub mod bvc;
+pub mod canon;
+mod lexer;
+pub mod parser;
+pub mod scripture_ref_builder;
+pub mod scripture_span;
+pub mod translation;
+pub mod versification;
+
+use bvc::{Book, Chapter, ChapterNumber, Verse, VerseNumber, VersePartLabel};
+use canon::{Canonical, ProtestantCanon};
+use lexer::{Lexer, Token};
+use parser::Parser;
+
+pub use scripture_ref_builder::ScriptureRef;
+
 // pub use bvc::*;
 
 pub struct ScriptureReferenceBuilder;
 
 impl ScriptureReferenceBuilder {}
 
-pub struct ScriptureReferenceSeeker;
+/// Finds the references in free text, like sermons, blog posts and notes.
+pub struct ScriptureReferenceSeeker<'c> {
+    canon: &'c dyn Canonical,
+}
+
+impl<'c> ScriptureReferenceSeeker<'c> {
+    /// Creates a seeker for the books of the Protestant canon.
+    pub fn new() -> Self {
+        Self::with_canon(&ProtestantCanon)
+    }
+
+    /// Creates a seeker for the books of the canon, e.g. to find "Tobit 1:1" in a Catholic
+    /// sermon.
+    pub fn with_canon(canon: &'c dyn Canonical) -> Self {
+        Self { canon }
+    }
+
+    /// Returns every reference in the text with the bytes it takes up, e.g. "John 3:16" and
+    /// 8..17 for "(99) as John 3:16 says".
+    ///
+    /// A reference starts with a book and ends where the text stops reading like a reference, so
+    /// punctuation around it is left out, e.g. "(Rom 8:28)." finds "Rom 8:28". Books must be
+    /// capitalized or numbered and have a chapter, since "John", "Acts" or "am 5" are more often
+    /// just words.
+    pub fn seek(&self, text: &str) -> Vec<(ScriptureRef, Range<usize>)> {
+        let mut found = Vec::new();
+        let mut start = 0;
+        while let Some(offset) = next_word(text, start) {
+            match self.reference_at(&text[offset..]) {
+                Some((reference, len)) => {
+                    found.push((reference, offset..offset + len));
+                    start = offset + len;
+                }
+                None => start = offset + text[offset..].chars().next().map_or(1, char::len_utf8),
+            }
+        }
+        found
+    }
+
+    /// Returns the longest reference at the start of the text and its length in bytes.
+    ///
+    /// The text is lexed up to the first token that cannot continue a reference, then trimmed
+    /// back to the last verse or chapter and parsed once, so seeking stays linear in long lists.
+    /// Should that fail, the reference is parsed once more without its last number, e.g. "John
+    /// 3:16, 99 sheep".
+    fn reference_at(&self, text: &str) -> Option<(ScriptureRef, usize)> {
+        if !text.starts_with(|c: char| c.is_uppercase() || c.is_ascii_digit()) {
+            return None;
+        }
+        lexer::book_at(text)?;
+        let mut lexer = Lexer::new(text);
+        let mut previous = None;
+        let mut lexed = 0;
+        let mut ends = (None, None);
+        // checked first, since the error for text that is no token copies all of the text
+        while lexer::starts_with_token(
+            &text[lexed..],
+            text[..lexed].ends_with(|c: char| c.is_ascii_digit()),
+        ) {
+            let Some(Ok(token)) = lexer.next() else {
+                break;
+            };
+            if previous.is_some_and(|previous| !continues(previous, token.token)) {
+                break;
+            }
+            previous = Some(token.token);
+            lexed = token.span.offset() + token.span.len();
+            // references end with a verse or chapter, not with a book or a separator
+            if matches!(
+                token.token,
+                Token::Number(_) | Token::Subverse(_) | Token::F | Token::FF
+            ) {
+                ends = (Some(lexed), ends.0);
+            }
+        }
+        [ends.0, ends.1].into_iter().flatten().find_map(|end| {
+            let reference = Parser::new(&text[..end]).parse_reference(self.canon).ok()?;
+            Some((reference, end))
+        })
+    }
+}
+
+impl Default for ScriptureReferenceSeeker<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the offset of the first word at or after the offset, where a reference may start.
+fn next_word(text: &str, offset: usize) -> Option<usize> {
+    text[offset..]
+        .char_indices()
+        .map(|(i, c)| (offset + i, c))
+        .find(|&(i, c)| c.is_alphanumeric() && !text[..i].ends_with(|c: char| c.is_alphanumeric()))
+        .map(|(i, _)| i)
+}
 
-impl ScriptureReferenceSeeker {}
+/// Returns whether the token may come after the previous one in a reference, e.g. a number after
+/// a colon but not after another number, as in "Psalm 23 4".
+fn continues(previous: Token, token: Token) -> bool {
+    match previous {
+        // e.g. "Psalm 151:4", whose name ends with a number
+        Token::Book(book) if book.has_one_chapter() && token == Token::Colon => true,
+        Token::Book(_) => matches!(token, Token::Number(_) | Token::Chapter | Token::Verse),
+        Token::Number(_) => !matches!(
+            token,
+            Token::Number(_) | Token::Book(_) | Token::Chapter | Token::Verse
+        ),
+        Token::Subverse(_) => !matches!(
+            token,
+            Token::Number(_) | Token::Book(_) | Token::Chapter | Token::Verse | Token::Subverse(_)
+        ),
+        Token::Colon | Token::Period | Token::Chapter | Token::Verse => {
+            matches!(token, Token::Number(_))
+        }
+        Token::Comma | Token::Dash | Token::SemiColon => matches!(
+            token,
+            Token::Number(_) | Token::Book(_) | Token::Chapter | Token::Verse
+        ),
+        Token::F | Token::FF => matches!(token, Token::Comma | Token::SemiColon),
+    }
+}
 
 pub struct ScriptureReferenceSorter;
 
@@ -17,3 +154,127 @@ impl ScriptureReferenceSorter {}
 pub struct ScriptureReferenceValidator;
 
 impl ScriptureReferenceValidator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canon::{CatholicCanon, OrthodoxCanon};
+
+    fn seek(text: &str) -> Vec<(String, &str)> {
+        ScriptureReferenceSeeker::new()
+            .seek(text)
+            .into_iter()
+            .map(|(reference, range)| (reference.to_string(), &text[range]))
+            .collect()
+    }
+
+    #[test]
+    fn find_references_in_sentences() {
+        let text = "As John 3:16 says, God loved the world; see also Rom 5:8.";
+        assert_eq!(
+            seek(text),
+            vec![
+                ("John 3:16".to_string(), "John 3:16"),
+                ("Romans 5:8".to_string(), "Rom 5:8"),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_references_around_punctuation_and_line_breaks() {
+        let text =
+            "Love is patient (1 Cor 13:4\u{2013}7).\nSee Ps 23,\nand Gen 50:26\u{2013}Ex 1:5!";
+        assert_eq!(
+            seek(text),
+            vec![
+                ("1 Corinthians 13:4-7".to_string(), "1 Cor 13:4\u{2013}7"),
+                ("Psalms 23".to_string(), "Ps 23"),
+                (
+                    "Genesis 50:26-Exodus 1:5".to_string(),
+                    "Gen 50:26\u{2013}Ex 1:5"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_lists_of_references() {
+        let text = "Read John 3:16, 18; 4:1-3; Rom 8:28 tonight";
+        let found = seek(text);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, "John 3:16, 18; 4:1-3; Rom 8:28");
+        assert_eq!(found[0].0, "John 3:16; John 3:18; John 4:1-3; Romans 8:28");
+    }
+
+    #[test]
+    fn byte_ranges_point_into_the_text() {
+        let text = "«Jean» — see Jude 5–7 ✝ and Matt 5";
+        let found = ScriptureReferenceSeeker::new().seek(text);
+        let ranges: Vec<&str> = found
+            .iter()
+            .map(|(_, range)| &text[range.clone()])
+            .collect();
+        assert_eq!(ranges, vec!["Jude 5–7", "Matt 5"]);
+    }
+
+    #[test]
+    fn find_books_of_the_chosen_canon() {
+        let text = "Tobit 1:1 and Sirach 2:1, not Romans 1:1";
+        let found: Vec<String> = ScriptureReferenceSeeker::with_canon(&CatholicCanon)
+            .seek(text)
+            .into_iter()
+            .map(|(reference, _)| reference.to_string())
+            .collect();
+        assert_eq!(found, vec!["Tobit 1:1", "Sirach 2:1", "Romans 1:1"]);
+        assert_eq!(seek(text), vec![("Romans 1:1".to_string(), "Romans 1:1")]);
+        let found: Vec<String> = ScriptureReferenceSeeker::with_canon(&OrthodoxCanon)
+            .seek("Sing Psalm 151:1 and Ps 151:4-5.")
+            .into_iter()
+            .map(|(reference, _)| reference.to_string())
+            .collect();
+        assert_eq!(found, vec!["Psalm 151:1", "Psalm 151:4-5"]);
+    }
+
+    #[test]
+    fn stop_at_tokens_that_cannot_continue_a_reference() {
+        assert_eq!(
+            seek("Read Psalm 23 4 times; John said so."),
+            vec![("Psalms 23".to_string(), "Psalm 23")]
+        );
+        assert_eq!(
+            seek("Rom 8:28; John 3:16ff, Acts"),
+            vec![(
+                "Romans 8:28; John 3:16-36".to_string(),
+                "Rom 8:28; John 3:16ff"
+            )]
+        );
+        assert_eq!(
+            seek("Read John 3:16, 99 sheep"),
+            vec![("John 3:16".to_string(), "John 3:16")]
+        );
+        let long = "See John 3:16, 18 and 1 Cor 13:4. ".repeat(2000);
+        assert_eq!(seek(&long).len(), 4000);
+    }
+
+    #[test]
+    fn find_long_lists_in_one_parse() {
+        // parsing every prefix of the list again would take minutes
+        let items: Vec<String> = (0..20_000).map(|i| format!("{}:1", i % 150 + 1)).collect();
+        let text = format!("See Ps {}.", items.join("; "));
+        let found = ScriptureReferenceSeeker::new().seek(&text);
+        assert_eq!(found.len(), 1);
+        let (reference, range) = &found[0];
+        assert_eq!(range.clone(), 4..text.len() - 1);
+        let ScriptureRef::Selection(selection) = reference else {
+            panic!("{reference} is not a list");
+        };
+        assert_eq!(selection.clone().parts().len(), 20_000);
+    }
+
+    #[test]
+    fn ignore_books_that_are_only_words() {
+        assert!(seek("John and Mark went to Acts of kindness.").is_empty());
+        assert!(seek("Mark 50 is no chapter, and I am 5 minutes late.").is_empty());
+        assert!(seek("").is_empty());
+    }
+}
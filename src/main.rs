@@ -1,17 +1,11 @@
-mod bvc;
-mod canon;
-mod lexer;
-mod parser;
-mod scripture_ref_builder;
-mod scripture_span;
-
-use bvc::{Book, Chapter, ChapterNumber, Spanned, Verse, VerseNumber, VersePartLabel};
-use canon::{InCanon, ProtestantCanon};
-use lexer::Lexer;
-use scripture_ref_builder::{
-    ScripturePassageRef, ScriptureRef, ScriptureSelectionRef, ScriptureVerseRef,
+use scripture_ref::{
+    ScriptureRef,
+    bvc::{Book, Chapter, ChapterNumber, Spanned, Verse, VerseNumber, VersePartLabel},
+    canon::{InCanon, ProtestantCanon},
+    parser,
+    scripture_ref_builder::{ScripturePassageRef, ScriptureSelectionRef, ScriptureVerseRef},
+    scripture_span::ScriptureSpan,
 };
-use scripture_span::ScriptureSpan;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let verse_ref = ScriptureVerseRef::builder()
@@ -130,7 +124,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("{:#034b}", book_span.start_position()?.get());
     println!("{:#034b}\n", book_span.end_position()?.get());
 
-    let chapter = Chapter::new(book, ChapterNumber::new(22)?)?;
+    let chapter_number = ChapterNumber::new(22)?;
+    let chapter = Chapter::new(book, chapter_number)?;
     let chapter_span = InCanon::new(chapter, &canon);
 
     println!("Chapter Span");
@@ -138,7 +133,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("{:#034b}", chapter_span.start_position()?.get());
     println!("{:#034b}\n", chapter_span.end_position()?.get());
 
-    let verse = Verse::new(book, chapter.number, VerseNumber::new(21)?)?;
+    let verse = Verse::new(book, chapter_number, VerseNumber::new(21)?)?;
     let verse_span = InCanon::new(verse, &canon);
 
     println!("Verse Span");
@@ -4,31 +4,31 @@ use super::operator::Op;
 pub enum BindingPower {
     Minimum,
     Book,
-    And,
-    Following,
 }
 
-pub fn postfix_binding_power(op: Op) -> u8 {
+pub fn postfix_binding_power(op: Op) -> Option<u8> {
     match op {
-        Op::Following => 20,
-        _ => panic!("bad postfix operator {op}"),
+        Op::Following => Some(20),
+        _ => None,
     }
 }
 
-pub fn prefix_binding_power(op: Op) -> u8 {
+pub fn prefix_binding_power(op: Op) -> Option<u8> {
     match op {
-        // Op::BookOf => 1,
-        _ => panic!("bad prefix operator {op}"),
+        // Op::BookOf => Some(1),
+        // markers bind lists and ranges but not what follows a ';', e.g. "vv. 4-6, 9; 5:1"
+        Op::Chapters | Op::Verses => Some(12),
+        _ => None,
     }
 }
 
-pub fn infix_binding_power(op: Op) -> (u8, u8) {
+pub fn infix_binding_power(op: Op) -> Option<(u8, u8)> {
     match op {
-        Op::BookOf => (9, 10),
-        Op::And => (11, 12),
-        Op::ChapterOf => (13, 14),
-        Op::Select => (15, 16),
-        Op::Through => (17, 18),
-        _ => (0, 0),
+        Op::BookOf => Some((9, 10)),
+        Op::And => Some((11, 12)),
+        Op::ChapterOf => Some((13, 14)),
+        Op::Select => Some((15, 16)),
+        Op::Through => Some((17, 18)),
+        Op::Chapters | Op::Following | Op::Verses => None,
     }
 }
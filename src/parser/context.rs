@@ -1,361 +1,587 @@
-use core::panic;
-use std::collections::HashMap;
-
-use crate::domain::book::Book;
-use crate::parser::token_tree::Node;
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum VerseRefNumber {
-    Verse(u8),
-    Phrase(u8, char),
+use std::fmt;
+
+use crate::{
+    bvc::{Book, Chapter, ChapterNumber, VerseNumber, VersePart},
+    canon::{Canonical, InCanon},
+    parser::token_tree::Node,
+    scripture_ref_builder::{
+        ScriptureBookRef, ScriptureChapterRef, ScripturePassageRef, ScriptureRef,
+        ScriptureSelectionRef, ScriptureVerseRef, SelectionPart,
+    },
+    scripture_span::{ScripturePosition, ScriptureSpan},
+};
+
+/// What the numbers of a node refer to, given the nodes around it, e.g. the 16 in "John 3:16" is
+/// a verse because it is in chapter 3 of John.
+#[derive(Debug, Clone, Copy)]
+enum Context {
+    /// No book has been named yet.
+    Root,
+    /// Numbers are chapters of the book.
+    Book(Book),
+    /// Numbers are verses of the chapter.
+    Chapter(Chapter),
 }
 
-type ChapterRefNumber = u8;
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct VerseRef(ChapterRefNumber, VerseRefNumber);
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct PassageRef(VerseRef, VerseRef);
+/// An error lowering a reference, along with the node it is about, if any, so that the parser can
+/// label where that node was written, e.g. the "99" of "John 3:99".
+#[derive(Debug)]
+pub(crate) struct LoweringError<'n> {
+    pub(crate) message: String,
+    pub(crate) node: Option<&'n Node>,
+}
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum VerseSelection {
-    VerseSelection(VerseRef),
-    PassageSelection(PassageRef),
+impl From<String> for LoweringError<'_> {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            node: None,
+        }
+    }
 }
 
-enum ScriptureRefNumber {
-    Chapter(u8),
-    Verse(u8, u8),
-    Phrase(u8, u8, char),
+impl fmt::Display for LoweringError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
 }
 
-struct ScriptureRefSelectionBuilder {
-    book: Option<Book>,
+/// Lowers a parsed reference into a validated reference, whose books must be in the canon.
+///
+/// IN_BOOK
+/// |__ Book(john)
+/// |__ AND               # ;
+///     |__ IN_CHAPTER
+///     |   |__ Number(2) # chapter
+///     |   |__ Number(1) # verse
+///     |__ IN_CHAPTER
+///         |__ Number(1) # chapter
+///         |__ Number(1) # verse
+pub(crate) fn lower<'n>(
+    node: &'n Node,
+    canon: &dyn Canonical,
+) -> Result<ScriptureRef, LoweringError<'n>> {
+    lower_from(node, canon, Context::Root)
 }
 
-impl ScriptureRefSelectionBuilder {
-    fn new() -> Self {
-        Self {
-            book: None,
-            // scripture: Vec::new(),
-        }
+/// Lowers a parsed reference written within the book, and the chapter if any, so that references
+/// without a book refer to them, e.g. "v. 7".
+pub(crate) fn lower_in<'n>(
+    node: &'n Node,
+    canon: &dyn Canonical,
+    book: Book,
+    chapter: Option<ChapterNumber>,
+) -> Result<ScriptureRef, LoweringError<'n>> {
+    if canon.book_position(book).is_none() {
+        return Err(format!("{book} is not in the canon").into());
     }
+    let context = match chapter {
+        Some(chapter) => Context::Chapter(Chapter::new(book, chapter)?),
+        None => book_context(book)?,
+    };
+    lower_from(node, canon, context)
+}
 
-    fn with_book(mut self, book: Book) -> Self {
-        self.book = Some(book);
-        self
+fn lower_from<'n>(
+    node: &'n Node,
+    canon: &dyn Canonical,
+    context: Context,
+) -> Result<ScriptureRef, LoweringError<'n>> {
+    let (mut parts, _) = Lowering { canon }.parts(node, context)?;
+    match parts.len() {
+        0 => Err("the reference is empty".to_string().into()),
+        1 => Ok(match parts.remove(0) {
+            SelectionPart::Book(book) => book.into(),
+            SelectionPart::Chapters(chapters) => chapters.into(),
+            SelectionPart::Verse(verse) => verse.into(),
+            SelectionPart::Passage(passage) => passage.into(),
+        }),
+        _ => Ok(ScriptureSelectionRef::new(parts)?.into()),
     }
+}
 
-    // fn add_scripture(mut self, scripture: ScriptureRef) -> Self {
-    //     self.scripture.push(scripture);
-    //     self
-    // }
+struct Lowering<'c> {
+    canon: &'c dyn Canonical,
 }
 
-// impl TryFrom<ScriptureRefSelectionBuilder> for ScriptureRefSelection {
-//     type Error = String;
-//
-//     fn try_from(builder: ScriptureRefSelectionBuilder) -> Result<Self, Self::Error> {
-//         todo!()
-//     }
-// }
+impl Lowering<'_> {
+    /// Lowers the node into the parts it refers to, along with the context it leaves for the parts
+    /// after it, e.g. after "John 3:16-4:2" numbers are verses of chapter 4.
+    fn parts<'n>(
+        &self,
+        node: &'n Node,
+        context: Context,
+    ) -> Result<(Vec<SelectionPart>, Context), LoweringError<'n>> {
+        // errors are about the innermost node they are found in
+        self.node_parts(node, context).map_err(|mut error| {
+            error.node.get_or_insert(node);
+            error
+        })
+    }
 
-// struct VerseRefNumber(ScriptureRefNumber);
-// struct PassageRefNumber(ScriptureRefNumber, ScriptureRefNumber);
+    fn node_parts<'n>(
+        &self,
+        node: &'n Node,
+        context: Context,
+    ) -> Result<(Vec<SelectionPart>, Context), LoweringError<'n>> {
+        match (node, context) {
+            (Node::InBook(book, node), _) => {
+                if self.canon.book_position(*book).is_none() {
+                    return Err(format!("{book} is not in the canon").into());
+                }
+                if **node == Node::Nil {
+                    let parts = vec![SelectionPart::Book(ScriptureBookRef::new(*book))];
+                    return Ok((parts, Context::Book(*book)));
+                }
+                self.parts(node, book_context(*book)?)
+            }
+            // a chapter may also be named within another, e.g. the end of "John 3:16-4:2"
+            (
+                Node::InChapter(chapter, node),
+                Context::Book(book) | Context::Chapter(Chapter { book, .. }),
+            ) => {
+                let chapter = Chapter::new(book, ChapterNumber::new(*chapter)?)?;
+                self.parts(node, Context::Chapter(chapter))
+            }
+            // ";" keeps the book, e.g. "John 3:16; 4:1", and "," keeps the chapter as well, e.g.
+            // "John 3:16, 18", but a book or chapter named on the right replaces them
+            (Node::And(..) | Node::Select(..), _) => self.list(node, context),
+            // either end may be part of a list, e.g. "Gen 50:26-Ex 1:5; 3:1" ends with Exodus
+            // 1:5 and adds Exodus 3:1, so the range joins the parts closest to the dash
+            (Node::Through(start, end), _) => {
+                let (mut parts, context) = self.parts(start, context)?;
+                let (after, context) = self.parts(end, context)?;
+                let mut after = after.into_iter();
+                let (Some(start), Some(end)) = (parts.pop(), after.next()) else {
+                    return Err(format!("{node} is missing the start or end of the range").into());
+                };
+                // whole books and chapters make a run of chapters, e.g. "Genesis 49-Exodus 2"
+                parts.push(match (first_chapter(&start), last_chapter(&end)) {
+                    (Some(start), Some(end)) => {
+                        self.check_chapter_order(start, end)?;
+                        SelectionPart::Chapters(ScriptureChapterRef::new(start, end)?)
+                    }
+                    _ => {
+                        let (start, end) = (first_verse(&start), last_verse(&end));
+                        self.check_order(start, end)?;
+                        SelectionPart::Passage(ScripturePassageRef::new(start, end)?)
+                    }
+                });
+                parts.extend(after);
+                Ok((parts, context))
+            }
+            // "ch." and "v." say what the numbers after them are, e.g. "ch. 5" within John 3
+            (
+                Node::Chapters(node),
+                Context::Book(book) | Context::Chapter(Chapter { book, .. }),
+            ) => self.parts(node, Context::Book(book)),
+            (Node::Verses(node), Context::Chapter(_)) => self.parts(node, context),
+            (Node::Verses(node), Context::Book(book)) => {
+                Err(format!("verses {node} of {book} need a chapter").into())
+            }
+            (Node::Following(verse, following), Context::Chapter(_)) => {
+                match self.part(verse, context)? {
+                    SelectionPart::Verse(verse) => Ok((
+                        vec![SelectionPart::Passage(ScripturePassageRef::following(
+                            verse, *following,
+                        )?)],
+                        context,
+                    )),
+                    part => Err(format!("{part} is not a verse that verses can follow").into()),
+                }
+            }
+            // "f" and "ff" follow verses, so "John 3ff" is not the chapters after John 3
+            (Node::Following(chapter, _), Context::Book(book)) => {
+                Err(format!("verses following chapter {chapter} of {book} need a verse").into())
+            }
+            // a whole chapter does not make its numbers verses, e.g. "Ps 23, 24" is two psalms
+            (Node::Number(chapter), Context::Book(book)) => {
+                let chapter = Chapter::new(book, ChapterNumber::new(*chapter)?)?;
+                Ok((vec![SelectionPart::Chapters(chapter.into())], context))
+            }
+            (Node::Number(verse), Context::Chapter(chapter)) => Ok((
+                vec![SelectionPart::Verse(
+                    ScriptureVerseRef::builder()
+                        .book(chapter.book)
+                        .chapter(chapter.number)
+                        .verse(VerseNumber::new(*verse)?)
+                        .build()?,
+                )],
+                context,
+            )),
+            (Node::VersePart(verse, part), Context::Chapter(chapter)) => Ok((
+                vec![SelectionPart::Verse(
+                    ScriptureVerseRef::builder()
+                        .book(chapter.book)
+                        .chapter(chapter.number)
+                        .verse(VerseNumber::new(*verse)?)
+                        .verse_part(*part)
+                        .build()?,
+                )],
+                context,
+            )),
+            (node, Context::Root) => Err(format!("{node} is not in a book").into()),
+            (node, _) => Err(format!("{node} is not a valid reference").into()),
+        }
+    }
 
-#[derive(Debug)]
-enum State {
-    Initial,
-    Operating,
-    Done,
-}
+    /// Lowers a list from left to right without recursing into its earlier items, which a long
+    /// list nests as deeply as it has items.
+    fn list<'n>(
+        &self,
+        mut node: &'n Node,
+        context: Context,
+    ) -> Result<(Vec<SelectionPart>, Context), LoweringError<'n>> {
+        let mut items = Vec::new();
+        while let Node::And(left, right) | Node::Select(left, right) = node {
+            items.push((matches!(node, Node::And(..)), right));
+            node = left;
+        }
+        let (mut parts, mut context) = self.parts(node, context)?;
+        for (and, right) in items.into_iter().rev() {
+            if and && let Context::Chapter(chapter) = context {
+                context = book_context(chapter.book)?;
+            }
+            let (right, after) = self.parts(right, context)?;
+            parts.extend(right);
+            context = after;
+        }
+        Ok((parts, context))
+    }
 
-#[derive(Debug)]
-pub struct ParsedScriptureRef {
-    pub book: Option<Book>,
-    // selection is a map of chapter to verse,passages
-    pub verse_selection: HashMap<u8, Vec<VerseSelection>>,
-}
+    /// Lowers a node that must be a single verse or passage, like either end of a range.
+    fn part<'n>(
+        &self,
+        node: &'n Node,
+        context: Context,
+    ) -> Result<SelectionPart, LoweringError<'n>> {
+        let (mut parts, _) = self.parts(node, context)?;
+        if parts.len() == 1 {
+            Ok(parts.remove(0))
+        } else {
+            Err(format!("{node} is not a single verse or passage").into())
+        }
+    }
 
-impl ParsedScriptureRef {
-    fn new() -> Self {
-        Self {
-            book: None,
-            verse_selection: HashMap::new(),
+    /// Checks that a range starts before it ends, placing both ends in the canon.
+    fn check_order(&self, start: ScriptureVerseRef, end: ScriptureVerseRef) -> Result<(), String> {
+        if self.position(start)? > self.position(end)? {
+            Err(format!("{start} comes after {end}"))
+        } else {
+            Ok(())
         }
     }
 
-    fn set_book(&mut self, book: Book) {
-        self.book = Some(book)
+    /// Checks that a run of chapters starts before it ends, placing both ends in the canon.
+    fn check_chapter_order(&self, start: Chapter, end: Chapter) -> Result<(), String> {
+        let (start_position, _) = ScriptureChapterRef::from(start).span(self.canon)?;
+        let (end_position, _) = ScriptureChapterRef::from(end).span(self.canon)?;
+        if start_position > end_position {
+            Err(format!("{start} comes after {end}"))
+        } else {
+            Ok(())
+        }
     }
 
-    fn add_verse_selection(&mut self, chapter: &u8, selection: VerseSelection) {
-        self.verse_selection
-            .entry(*chapter)
-            .and_modify(|v| v.push(selection))
-            .or_insert_with(|| vec![selection]);
+    /// The position of the verse, or of its part, in the canon.
+    fn position(&self, verse: ScriptureVerseRef) -> Result<ScripturePosition, String> {
+        let part = verse.verse_part();
+        let verse = verse.english_verse();
+        match part {
+            Some(part) => InCanon::new(
+                VersePart {
+                    book: verse.book,
+                    chapter: verse.chapter,
+                    verse,
+                    part,
+                },
+                self.canon,
+            )
+            .start_position(),
+            None => InCanon::new(verse, self.canon).start_position(),
+        }
     }
 }
 
-#[derive(Debug)]
-pub struct Context {
-    state: State,
-    pub result: ParsedScriptureRef,
+/// The context of the numbers after a book, which are verses in a book of one chapter, e.g.
+/// "Jude 5".
+fn book_context(book: Book) -> Result<Context, String> {
+    if book.has_one_chapter() {
+        Ok(Context::Chapter(Chapter::new(
+            book,
+            ChapterNumber::new(1)?,
+        )?))
+    } else {
+        Ok(Context::Book(book))
+    }
 }
 
-impl Context {
-    pub fn new() -> Self {
-        Self {
-            state: State::Initial,
-            result: ParsedScriptureRef::new(),
-        }
+fn first_chapter(part: &SelectionPart) -> Option<Chapter> {
+    match part {
+        SelectionPart::Book(book) => Some(ScriptureChapterRef::from(*book).start()),
+        SelectionPart::Chapters(chapters) => Some(chapters.start()),
+        SelectionPart::Verse(_) | SelectionPart::Passage(_) => None,
     }
+}
 
-    // IN_BOOK
-    // |__ Book(john)
-    // |__ AND               # ;
-    //     |__ IN_CHAPTER
-    //     |   |__ Number(2) # chapter
-    //     |   |__ Number(1) # verse
-    //     |__ IN_CHAPTER
-    //         |__ Number(1) # chapter
-    //         |__ Number(1) # verse
-    //
-    // IN_BOOK
-    // |__ Book(john)
-    // |__ IN_CHAPTER
-    //     |__ Number(1)         # chapter
-    //     |__ SELECT            # ,
-    //         |__ Number(5)     # verse
-    //         |__ THROUGH       # -
-    //             |__ Number(2) # verse
-    //             |__ Number(1) # verse
-
-    pub fn transition(&mut self, node: Node) -> Result<(), miette::Error> {
-        match self.state {
-            State::Initial => self.process_node(node),
-            State::Operating => {
-                self.state = State::Done;
-                Ok(())
-            }
-            State::Done => Ok(()), // stop processing
-        }
+fn last_chapter(part: &SelectionPart) -> Option<Chapter> {
+    match part {
+        SelectionPart::Book(book) => Some(ScriptureChapterRef::from(*book).end()),
+        SelectionPart::Chapters(chapters) => Some(chapters.end()),
+        SelectionPart::Verse(_) | SelectionPart::Passage(_) => None,
     }
+}
 
-    fn process_node(&mut self, node: Node) -> Result<(), miette::Error> {
-        match node {
-            Node::And(_, _) => todo!(),
-            Node::Book(book) => {
-                self.state = State::Operating;
-                self.result.set_book(book);
-                Ok(())
-            }
-            Node::InBook(book, _) => {
-                self.state = State::Operating;
-                self.result.set_book(book);
-                Ok(())
-            }
-            Node::InChapter(chapter, verse_node) => self.process_verse_node(chapter, *verse_node),
-            Node::Through(lhs, rhs) => {
-                self.process_node(*lhs);
-                self.process_node(*rhs);
-                Ok(())
-            }
-            Node::Select(_, _) => todo!(),
-            Node::Number(_) => todo!(),
-            Node::Nil => todo!(),
+fn first_verse(part: &SelectionPart) -> ScriptureVerseRef {
+    match part {
+        SelectionPart::Book(book) => {
+            ScripturePassageRef::from(ScriptureChapterRef::from(*book)).start()
         }
+        SelectionPart::Chapters(chapters) => ScripturePassageRef::from(*chapters).start(),
+        SelectionPart::Verse(verse) => *verse,
+        SelectionPart::Passage(passage) => passage.start(),
     }
+}
 
-    fn process_verse_node(&mut self, chapter: u8, node: Node) -> Result<(), miette::Error> {
-        match node {
-            Node::And(first, remaining) => match (*first, *remaining) {
-                // TODO: Does joining these improve performance/maintainabilty at the expense of
-                // easy sorting?
-                (Node::Number(v), rhs) => {
-                    self.result.add_verse_selection(
-                        &chapter,
-                        VerseSelection::VerseSelection(VerseRef(chapter, VerseRefNumber::Verse(v))),
-                    );
-                    self.process_verse_node(chapter, rhs)?;
-                    Ok(())
-                }
-                (lhs, Node::Number(v)) => {
-                    self.process_verse_node(chapter, lhs)?;
-                    self.result.add_verse_selection(
-                        &chapter,
-                        VerseSelection::VerseSelection(VerseRef(chapter, VerseRefNumber::Verse(v))),
-                    );
-                    Ok(())
-                }
-                (lhs, rhs) => {
-                    self.process_verse_node(chapter, lhs)?;
-                    self.process_verse_node(chapter, rhs)?;
-                    Ok(())
-                }
-            },
-            Node::InChapter(_, _) => todo!(),
-            Node::Through(start, end) => match (*start, *end) {
-                (Node::Number(s), Node::Number(e)) => {
-                    self.result.add_verse_selection(
-                        &chapter,
-                        VerseSelection::PassageSelection(PassageRef(
-                            VerseRef(chapter, VerseRefNumber::Verse(s)),
-                            VerseRef(chapter, VerseRefNumber::Verse(e)),
-                        )),
-                    );
-                    Ok(())
-                }
-                _ => panic!("must have start and end numbers"),
-            },
-            Node::Select(_, _) => todo!(),
-            Node::Number(verse_number) => {
-                self.result.add_verse_selection(
-                    &chapter,
-                    VerseSelection::VerseSelection(VerseRef(
-                        chapter,
-                        VerseRefNumber::Verse(verse_number),
-                    )),
-                );
-                Ok(())
-            }
-            other => {
-                miette::bail!("cannot process {other} as a verse node");
-            }
+fn last_verse(part: &SelectionPart) -> ScriptureVerseRef {
+    match part {
+        SelectionPart::Book(book) => {
+            ScripturePassageRef::from(ScriptureChapterRef::from(*book)).end()
         }
+        SelectionPart::Chapters(chapters) => ScripturePassageRef::from(*chapters).end(),
+        SelectionPart::Verse(verse) => *verse,
+        SelectionPart::Passage(passage) => passage.end(),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::{
-        context::{PassageRef, VerseRef, VerseRefNumber, VerseSelection},
-        token_tree::Node,
-    };
+    use super::*;
+    use crate::canon::{CatholicCanon, ProtestantCanon, TanakhCanon};
+
+    fn lower(node: Node) -> Result<String, String> {
+        super::lower(&node, &ProtestantCanon)
+            .map(|reference| reference.to_string())
+            .map_err(|error| error.message)
+    }
+
+    fn in_john(node: Node) -> Node {
+        Node::InBook(Book::John, Box::new(node))
+    }
+
+    fn in_chapter(chapter: u8, node: Node) -> Node {
+        Node::InChapter(chapter, Box::new(node))
+    }
 
-    use super::Context;
+    fn through(start: Node, end: Node) -> Node {
+        Node::Through(Box::new(start), Box::new(end))
+    }
 
     #[test]
-    fn process_in_chapter_node_with_single_verse() {
-        let ast = Node::InChapter(1, Box::new(Node::Number(1)));
-        let mut context = Context::new();
-        context.transition(ast).expect("failed to transition ast");
-        assert_eq!(
-            context.result.verse_selection.get(&1),
-            Some(&vec![VerseSelection::VerseSelection(VerseRef(
-                1,
-                VerseRefNumber::Verse(1)
-            ))])
-        );
+    fn lower_a_single_verse() {
+        let reference = lower(in_john(in_chapter(3, Node::Number(16))));
+        assert_eq!(reference.as_deref(), Ok("John 3:16"));
     }
 
     #[test]
-    fn process_in_chapter_node_with_consecutive_verses() {
-        let ast = Node::InChapter(
+    fn lower_consecutive_verses() {
+        let ast = in_john(in_chapter(1, through(Node::Number(1), Node::Number(3))));
+        let reference = super::lower(&ast, &ProtestantCanon).unwrap();
+        let ScriptureRef::Passage(passage) = reference else {
+            panic!("expected a passage, not {reference}");
+        };
+        assert_eq!(passage.start().to_string(), "John 1:1");
+        assert_eq!(passage.end().to_string(), "John 1:3");
+    }
+
+    #[test]
+    fn lower_nonconsecutive_and_consecutive_verses() {
+        let ast = in_john(in_chapter(
             1,
-            Box::new(Node::Through(
+            Node::Select(
                 Box::new(Node::Number(1)),
-                Box::new(Node::Number(3)),
+                Box::new(through(Node::Number(3), Node::Number(5))),
+            ),
+        ));
+        let ScriptureRef::Selection(selection) = super::lower(&ast, &ProtestantCanon).unwrap()
+        else {
+            panic!("expected a selection");
+        };
+        let parts = selection.parts();
+        assert_eq!(parts.len(), 2);
+        assert!(matches!(parts[0], SelectionPart::Verse(_)));
+        assert!(matches!(parts[1], SelectionPart::Passage(_)));
+    }
+
+    #[test]
+    fn lower_verses_in_several_chapters() {
+        let ast = in_john(Node::And(
+            Box::new(in_chapter(3, Node::Number(16))),
+            Box::new(in_chapter(4, Node::Number(2))),
+        ));
+        assert_eq!(lower(ast).as_deref(), Ok("John 3:16; John 4:2"));
+    }
+
+    #[test]
+    fn lower_consecutive_verses_through_multiple_chapters() {
+        let ast = in_john(through(
+            in_chapter(1, Node::Number(1)),
+            in_chapter(2, Node::Number(2)),
+        ));
+        let ScriptureRef::Passage(passage) = super::lower(&ast, &ProtestantCanon).unwrap() else {
+            panic!("expected a passage");
+        };
+        assert_eq!(passage.start().to_string(), "John 1:1");
+        assert_eq!(passage.end().to_string(), "John 2:2");
+    }
+
+    #[test]
+    fn lower_ranges_across_books() {
+        let ast = Node::InBook(
+            Book::Genesis,
+            Box::new(in_chapter(
+                50,
+                through(
+                    Node::Number(26),
+                    Node::InBook(
+                        Book::Exodus,
+                        Box::new(Node::And(
+                            Box::new(in_chapter(1, Node::Number(5))),
+                            Box::new(in_chapter(3, Node::Number(1))),
+                        )),
+                    ),
+                ),
             )),
         );
-        let mut context = Context::new();
-        context.transition(ast).expect("failed to transition ast");
         assert_eq!(
-            context.result.verse_selection.get(&1),
-            Some(&vec![VerseSelection::PassageSelection(PassageRef(
-                VerseRef(1, VerseRefNumber::Verse(1)),
-                VerseRef(1, VerseRefNumber::Verse(3)),
-            ))])
+            lower(ast).as_deref(),
+            Ok("Genesis 50:26-Exodus 1:5; Exodus 3:1")
         );
     }
 
     #[test]
-    fn process_in_chapter_node_with_nonconsecutive_verses() {
-        let ast = Node::InChapter(
-            1,
-            Box::new(Node::And(
-                Box::new(Node::Number(1)),
-                Box::new(Node::Number(3)),
+    fn ranges_follow_the_order_of_the_canon() {
+        // the Tanakh places the prophets before the writings
+        let ast = Node::InBook(
+            Book::Psalms,
+            Box::new(in_chapter(
+                150,
+                through(
+                    Node::Number(6),
+                    Node::InBook(Book::Malachi, Box::new(in_chapter(1, Node::Number(1)))),
+                ),
             )),
         );
-        let mut context = Context::new();
-        context.transition(ast).expect("failed to transition ast");
+        assert!(super::lower(&ast, &ProtestantCanon).is_ok());
         assert_eq!(
-            context.result.verse_selection.get(&1),
-            Some(&vec![
-                VerseSelection::VerseSelection(VerseRef(1, VerseRefNumber::Verse(1))),
-                VerseSelection::VerseSelection(VerseRef(1, VerseRefNumber::Verse(3)))
-            ])
+            super::lower(&ast, &TanakhCanon)
+                .map(|_| ())
+                .map_err(|error| error.message),
+            Err("Psalms 150:6 comes after Malachi 1:1".to_string())
         );
     }
 
     #[test]
-    fn process_in_chapter_node_with_nonconsecutive_and_consecutive_verses() {
-        let ast = Node::InChapter(
-            1,
-            Box::new(Node::And(
-                Box::new(Node::Number(1)),
-                Box::new(Node::Through(
-                    Box::new(Node::Number(3)),
-                    Box::new(Node::Number(5)),
-                )),
-            )),
-        );
-        let mut context = Context::new();
-        context.transition(ast).expect("failed to transition ast");
+    fn lists_inherit_the_book_and_chapter_before_them() {
+        let and = |left, right| Node::And(Box::new(left), Box::new(right));
+        let select = |left, right| Node::Select(Box::new(left), Box::new(right));
+        // John 3:16-4:2, 5; 6
+        let ast = in_john(and(
+            in_chapter(
+                3,
+                select(
+                    through(Node::Number(16), in_chapter(4, Node::Number(2))),
+                    Node::Number(5),
+                ),
+            ),
+            Node::Number(6),
+        ));
+        assert_eq!(lower(ast).as_deref(), Ok("John 3:16-4:2; John 4:5; John 6"));
+        // John 3:16; Rom 8:28, 31
+        let ast = in_john(and(
+            in_chapter(3, Node::Number(16)),
+            Node::InBook(
+                Book::Romans,
+                Box::new(in_chapter(8, select(Node::Number(28), Node::Number(31)))),
+            ),
+        ));
         assert_eq!(
-            context.result.verse_selection.get(&1),
-            Some(&vec![
-                VerseSelection::VerseSelection(VerseRef(1, VerseRefNumber::Verse(1))),
-                VerseSelection::PassageSelection(PassageRef(
-                    VerseRef(1, VerseRefNumber::Verse(3)),
-                    VerseRef(1, VerseRefNumber::Verse(5))
-                ))
-            ])
+            lower(ast).as_deref(),
+            Ok("John 3:16; Romans 8:28; Romans 8:31")
         );
     }
 
     #[test]
-    fn process_in_chapter_node_with_consecutive_and_nonconsecutive_verses() {
-        let ast = Node::InChapter(
-            1,
-            Box::new(Node::And(
-                Box::new(Node::Through(
-                    Box::new(Node::Number(1)),
-                    Box::new(Node::Number(3)),
-                )),
-                Box::new(Node::Number(5)),
+    fn lower_whole_books_and_chapters() {
+        assert_eq!(lower(in_john(Node::Nil)).as_deref(), Ok("John"));
+        assert!(matches!(
+            super::lower(&in_john(Node::Number(3)), &ProtestantCanon),
+            Ok(ScriptureRef::Chapters(_))
+        ));
+        let ast = Node::InBook(
+            Book::Romans,
+            Box::new(through(Node::Number(8), Node::Number(9))),
+        );
+        assert_eq!(lower(ast).as_deref(), Ok("Romans 8-9"));
+        let ast = Node::InBook(
+            Book::Genesis,
+            Box::new(through(
+                Node::Number(49),
+                Node::InBook(Book::Exodus, Box::new(Node::Nil)),
             )),
         );
-        let mut context = Context::new();
-        context.transition(ast).expect("failed to transition ast");
+        assert_eq!(lower(ast).as_deref(), Ok("Genesis 49-Exodus 40"));
+    }
+
+    #[test]
+    fn lower_ranges_from_whole_chapters_to_verses() {
+        let ast = in_john(through(Node::Number(3), in_chapter(4, Node::Number(2))));
+        assert_eq!(lower(ast).as_deref(), Ok("John 3:1-4:2"));
+    }
+
+    #[test]
+    fn lower_numbers_in_single_chapter_books_as_verses() {
+        let jude = |node| Node::InBook(Book::Jude, Box::new(node));
+        assert_eq!(lower(jude(Node::Number(5))).as_deref(), Ok("Jude 5"));
+        assert_eq!(
+            lower(jude(through(Node::Number(5), Node::Number(7)))).as_deref(),
+            Ok("Jude 5-7")
+        );
         assert_eq!(
-            context.result.verse_selection.get(&1),
-            Some(&vec![
-                VerseSelection::PassageSelection(PassageRef(
-                    VerseRef(1, VerseRefNumber::Verse(1)),
-                    VerseRef(1, VerseRefNumber::Verse(3))
-                )),
-                VerseSelection::VerseSelection(VerseRef(1, VerseRefNumber::Verse(5))),
-            ])
+            lower(jude(in_chapter(1, Node::Number(5)))).as_deref(),
+            Ok("Jude 5")
         );
+        assert_eq!(lower(jude(Node::Nil)).as_deref(), Ok("Jude"));
+        assert!(lower(jude(in_chapter(2, Node::Number(5)))).is_err());
+    }
+
+    #[test]
+    fn reject_verses_that_do_not_exist() {
+        assert!(lower(in_john(in_chapter(3, Node::Number(37)))).is_err());
+        assert!(lower(in_john(in_chapter(22, Node::Number(1)))).is_err());
     }
 
     #[test]
-    fn process_consecutive_verses_through_multiple_chapters() {
-        let ast = Node::Through(
-            Box::new(Node::InChapter(1, Box::new(Node::Number(1)))),
-            Box::new(Node::InChapter(2, Box::new(Node::Number(2)))),
+    fn reject_ranges_that_end_before_they_start() {
+        let ast = in_john(in_chapter(3, through(Node::Number(18), Node::Number(16))));
+        assert_eq!(
+            lower(ast),
+            Err("John 3:18 comes after John 3:16".to_string())
         );
-        let mut context = Context::new();
-        context.transition(ast).expect("failed to transition ast");
+    }
+
+    #[test]
+    fn reject_books_outside_the_canon() {
+        let ast = Node::InBook(Book::Tobit, Box::new(in_chapter(1, Node::Number(1))));
         assert_eq!(
-            context.result.verse_selection.get(&1),
-            Some(&vec![VerseSelection::PassageSelection(PassageRef(
-                VerseRef(1, VerseRefNumber::Verse(1)),
-                VerseRef(2, VerseRefNumber::Verse(2))
-            )),])
+            lower(ast.clone()),
+            Err("Tobit is not in the canon".to_string())
         );
+        assert!(super::lower(&ast, &CatholicCanon).is_ok());
+    }
+
+    #[test]
+    fn reject_numbers_without_a_book() {
+        assert!(lower(in_chapter(3, Node::Number(16))).is_err());
     }
 }
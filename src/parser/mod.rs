@@ -1,28 +1,54 @@
 use std::iter::Peekable;
 
-use crate::{bvc::Book, lexer::Token};
-use binding_power::{BindingPower, infix_binding_power};
-use miette::miette;
+use miette::{LabeledSpan, SourceSpan};
+
+use crate::lexer::{SpannedToken, Token};
+use binding_power::{
+    BindingPower, infix_binding_power, postfix_binding_power, prefix_binding_power,
+};
+use context::LoweringError;
 use operator::Op;
+use options::ParseOptions;
 use token_tree::Node;
 
-use crate::Lexer;
+use crate::{
+    Lexer,
+    bvc::{Book, ChapterNumber},
+    canon::Canonical,
+    scripture_ref_builder::{FollowingVerses, ScriptureRef},
+};
 
 pub mod binding_power;
-// pub mod context;
-pub mod operand;
+pub mod context;
 pub mod operator;
+pub mod options;
 // pub mod state_machine;
 pub mod token_tree;
 
+/// How deeply operators can be nested, so that untrusted input cannot overflow the stack.
+const MAX_DEPTH: usize = 64;
+
 pub struct Parser<'de> {
+    input: &'de str,
     lexer: Peekable<Lexer<'de>>,
+    options: ParseOptions,
+    depth: usize,
+    /// The spans of the books and numbers parsed so far, in the order they were written.
+    written: Vec<SourceSpan>,
 }
 
 impl<'de> Parser<'de> {
     pub fn new(input: &'de str) -> Self {
+        Self::with_options(input, ParseOptions::default())
+    }
+
+    pub fn with_options(input: &'de str, options: ParseOptions) -> Self {
         Self {
+            input,
             lexer: Lexer::new(input).peekable(),
+            options,
+            depth: 0,
+            written: Vec::new(),
         }
     }
 
@@ -30,88 +56,340 @@ impl<'de> Parser<'de> {
         self.parse_expression(BindingPower::Minimum as u8)
     }
 
-    fn current(&mut self) -> Result<Option<Token>, miette::Error> {
+    /// Parses the input into a validated reference, whose books must be in the canon.
+    pub fn parse_reference(
+        &mut self,
+        canon: &dyn Canonical,
+    ) -> Result<ScriptureRef, miette::Error> {
+        let node = self.parse()?;
+        context::lower(&node, canon).map_err(|e| self.lowering_error(&node, e))
+    }
+
+    /// Parses the input into a validated reference like [`Parser::parse_reference`], taking the
+    /// book, and the chapter if any, from where the reference was written, e.g. "v. 7" in notes on
+    /// John 3.
+    pub fn parse_reference_in(
+        &mut self,
+        canon: &dyn Canonical,
+        book: Book,
+        chapter: Option<ChapterNumber>,
+    ) -> Result<ScriptureRef, miette::Error> {
+        let node = self.parse()?;
+        context::lower_in(&node, canon, book, chapter).map_err(|e| self.lowering_error(&node, e))
+    }
+
+    /// Reports an error lowering the parsed reference, labelled at the books and numbers of the
+    /// node it is about, e.g. the "99" of "John 3:99", or at the whole reference otherwise.
+    fn lowering_error(&self, root: &Node, error: LoweringError) -> miette::Error {
+        let whole = || self.error_at((0, self.input.len()).into(), "this reference", &error);
+        let Some(mut node) = error.node else {
+            return whole();
+        };
+        // a range starts with the book and chapter it is in, e.g. "John 4:2-3:16" rather than
+        // "2-3:16"
+        let encloses = |parent: &Node, node: &Node| match parent {
+            Node::InBook(_, child) | Node::InChapter(_, child) => std::ptr::eq(&**child, node),
+            _ => false,
+        };
+        if matches!(node, Node::Through(..)) {
+            while let Some(parent) = root.preorder().find(|n| encloses(n, node)) {
+                node = parent;
+            }
+        }
+        let start = root
+            .preorder()
+            .take_while(|n| !std::ptr::eq(*n, node))
+            .filter(|n| n.is_written())
+            .count();
+        let within = node.preorder().filter(|n| n.is_written()).count();
+        // books and chapters are labelled on their own, since their errors are about them
+        let (count, label) = match (node, error.node) {
+            (_, Some(Node::Through(..))) => (within, "this range"),
+            (Node::InBook(..), _) => (1, "this book"),
+            (Node::InChapter(..), _) => (1, "this chapter"),
+            (Node::Number(_) | Node::VersePart(..), _) => (1, "this number"),
+            _ => (within, "this reference"),
+        };
+        let Some(last) = (start + count).checked_sub(1) else {
+            return whole();
+        };
+        let (Some(first), Some(last)) = (self.written.get(start), self.written.get(last)) else {
+            return whole();
+        };
+        let end = last.offset() + last.len();
+        self.error_at((first.offset(), end - first.offset()).into(), label, &error)
+    }
+
+    fn current(&mut self) -> Result<Option<SpannedToken>, miette::Error> {
         self.lexer
             .next()
             .transpose()
             .map_err(|e| e.wrap_err("parsing current token"))
     }
 
+    /// Reports an error labelled at the span of the input it is about.
+    fn error_at(
+        &self,
+        span: SourceSpan,
+        label: &str,
+        message: impl std::fmt::Display,
+    ) -> miette::Error {
+        miette::miette! {
+            labels = vec![LabeledSpan::at(span, label)],
+            "{message}"
+        }
+        .with_source_code(self.input.to_string())
+    }
+
+    /// Reports the separator or word at the span if the input ends right after it, e.g. the '-'
+    /// of "Rom 8:28-", instead of leaving nothing for it to join.
+    fn expect_after(&mut self, span: SourceSpan, expected: &str) -> Result<(), miette::Error> {
+        if self.lexer.peek().is_some() {
+            return Ok(());
+        }
+        Err(self.error_at(
+            span,
+            "nothing follows this",
+            format!("expected {expected} after '{}'", self.text(span)),
+        ))
+    }
+
+    /// The text of the input a token was lexed from, e.g. "–" for a dash.
+    fn text(&self, span: SourceSpan) -> &'de str {
+        &self.input[span.offset()..span.offset() + span.len()]
+    }
+
     fn parse_expression(&mut self, min_bp: u8) -> Result<Node, miette::Error> {
+        if self.depth == MAX_DEPTH {
+            let span = match self.lexer.peek() {
+                Some(Ok(token)) => token.span,
+                _ => (self.input.len(), 0).into(),
+            };
+            return Err(self.error_at(span, "here", "the reference is nested too deeply"));
+        }
+        self.depth += 1;
+        let node = self.parse_nested_expression(min_bp);
+        self.depth -= 1;
+        node
+    }
+
+    /// Parses the verse of a range or list item in another chapter, e.g. the "2" of "3:16-4:2"
+    /// or "3:16, 4:2", since the chapter separator binds less tightly than ranges and lists.
+    fn parse_chapter_change(&mut self, rhs: Node, r_bp: u8) -> Result<Node, miette::Error> {
+        let Node::Number(chapter) = rhs else {
+            return Ok(rhs);
+        };
+        let convention = self.options.separator_convention();
+        let changes_chapter = self.lexer.next_if(|token| {
+            token
+                .as_ref()
+                .is_ok_and(|t| convention.operator(t.token) == Some(Op::ChapterOf))
+        });
+        let Some(Ok(separator)) = changes_chapter else {
+            return Ok(rhs);
+        };
+        self.expect_after(separator.span, "a verse")?;
+        let verse = self.parse_expression(r_bp)?;
+        Ok(Node::InChapter(chapter, Box::new(verse)))
+    }
+
+    fn parse_nested_expression(&mut self, min_bp: u8) -> Result<Node, miette::Error> {
         let current = self.current()?;
-        let current = match current {
+        let SpannedToken {
+            token: current,
+            span,
+        } = match current {
             Some(t) => t,
             None => return Ok(Node::Nil),
         };
 
         let mut lhs = match current {
-            Token::Book(b) => {
-                let book = Book::try_from((None, &b)).map_err(|e| miette!("{e}"))?;
-                self.lexer.next();
-                let right = self.parse_expression(BindingPower::Book as u8)?;
+            Token::Book(book) => {
+                self.written.push(span);
+                // a book whose name ends with a number has its verses after a colon, e.g.
+                // "Psalm 151:4", where other books would have a chapter
+                if book.has_one_chapter() && self.text(span).ends_with(|c: char| c.is_ascii_digit())
+                {
+                    let convention = self.options.separator_convention();
+                    let separator = self.lexer.next_if(|token| {
+                        token
+                            .as_ref()
+                            .is_ok_and(|t| convention.operator(t.token) == Some(Op::ChapterOf))
+                    });
+                    if let Some(Ok(separator)) = separator {
+                        self.expect_after(separator.span, "a verse")?;
+                    }
+                }
+                // a whole book may start a range or a list, e.g. "Genesis-Exodus; Leviticus 1"
+                let whole_book = matches!(
+                    self.lexer.peek(),
+                    Some(Ok(SpannedToken {
+                        token: Token::Dash | Token::SemiColon,
+                        ..
+                    }))
+                );
+                let right = if whole_book {
+                    Node::Nil
+                } else {
+                    self.parse_expression(BindingPower::Book as u8)?
+                };
                 Node::InBook(book, Box::new(right))
             }
-            Token::Colon => todo!(),
-            Token::Comma => todo!(),
-            Token::Dash => todo!(),
-            Token::FF => todo!(),
             Token::Number(n) => {
-                let peeked = self.lexer.next_if(|v| matches!(v, Ok(Token::Book(_))));
-                if let Some(Ok(Token::Book(b))) = peeked {
-                    let book = Book::try_from((Some(n), &b)).map_err(|e| miette!("{e}"))?;
-                    let right = self.parse_expression(BindingPower::Book as u8)?;
-                    Node::InBook(book, Box::new(right))
-                } else {
-                    Node::Number(n)
+                let part = self.lexer.next_if(|v| {
+                    matches!(
+                        v,
+                        Ok(SpannedToken {
+                            token: Token::Subverse(_),
+                            ..
+                        })
+                    )
+                });
+                match part {
+                    Some(Ok(SpannedToken {
+                        token: Token::Subverse(part),
+                        span: part_span,
+                    })) => {
+                        let end = part_span.offset() + part_span.len();
+                        self.written
+                            .push((span.offset(), end - span.offset()).into());
+                        Node::VersePart(n, part)
+                    }
+                    _ => {
+                        self.written.push(span);
+                        Node::Number(n)
+                    }
                 }
             }
-            Token::Period => todo!(),
-            Token::SemiColon => todo!(),
-            Token::Subverse => todo!(),
+            Token::Chapter | Token::Verse => {
+                let op = match current {
+                    Token::Chapter => Op::Chapters,
+                    _ => Op::Verses,
+                };
+                let r_bp = prefix_binding_power(op).ok_or_else(|| {
+                    self.error_at(span, "this word", format!("{op} cannot start a reference"))
+                })?;
+                self.expect_after(span, "a number")?;
+                let right = Box::new(self.parse_expression(r_bp)?);
+                match op {
+                    Op::Chapters => Node::Chapters(right),
+                    _ => Node::Verses(right),
+                }
+            }
+            Token::Subverse(part) => {
+                return Err(self.error_at(
+                    span,
+                    "this letter",
+                    format!("verse part '{part}' does not follow a verse"),
+                ));
+            }
+            Token::Colon
+            | Token::Comma
+            | Token::Dash
+            | Token::F
+            | Token::FF
+            | Token::Period
+            | Token::SemiColon => {
+                return Err(self.error_at(
+                    span,
+                    "this separator",
+                    format!("expected a book or a number, not '{}'", self.text(span)),
+                ));
+            }
         };
 
         loop {
-            let token = self.lexer.peek();
-            let operator = match token {
+            let (token, span) = match self.lexer.peek() {
                 None => break,
-                Some(Ok(Token::Number(_))) => {
-                    // let chapter = *n;
-                    // let rhs = self.parse_expression(min_bp)?;
-                    // return Ok(Node::InChapter(chapter, Box::new(rhs)));
-                    let rhs = self.parse_expression(min_bp);
-                    return rhs;
+                Some(Ok(SpannedToken { token, span })) => (*token, *span),
+                Some(Err(_)) => {
+                    // takes the peeked error, so that it is returned by the `?`
+                    self.current()?;
+                    break;
+                }
+            };
+            let operator = match token {
+                Token::Colon | Token::Comma | Token::Period => {
+                    let convention = self.options.separator_convention();
+                    convention.operator(token).ok_or_else(|| {
+                        self.error_at(
+                            span,
+                            "this separator",
+                            format!(
+                                "unexpected '{}' in a reference written with {} separators",
+                                self.text(span),
+                                convention
+                            ),
+                        )
+                    })?
+                }
+                Token::F | Token::FF => {
+                    let op = Op::Following;
+                    if postfix_binding_power(op).is_none_or(|bp| bp < min_bp) {
+                        break;
+                    }
+                    self.lexer.next();
+                    let following = match token {
+                        Token::F => FollowingVerses::Next,
+                        _ => FollowingVerses::RestOfChapter,
+                    };
+                    lhs = Node::Following(Box::new(lhs), following);
+                    continue;
                 }
-                Some(Ok(Token::Comma)) => Op::Select,
-                Some(Ok(Token::Colon)) => Op::ChapterOf,
-                Some(Ok(Token::Dash)) => Op::Through,
-                Some(Ok(Token::SemiColon)) => Op::And,
-                Some(_) => {
-                    todo!("handle other tokens")
+                Token::Dash => Op::Through,
+                Token::SemiColon => Op::And,
+                Token::Book(_)
+                | Token::Chapter
+                | Token::Number(_)
+                | Token::Subverse(_)
+                | Token::Verse => {
+                    return Err(self.error_at(
+                        span,
+                        "expected a separator before this",
+                        format!("unexpected '{}' after {lhs}", self.text(span)),
+                    ));
                 }
             };
 
-            let (l_bp, r_bp) = infix_binding_power(operator);
+            let Some((l_bp, r_bp)) = infix_binding_power(operator) else {
+                return Err(self.error_at(
+                    span,
+                    "this operator",
+                    format!("{operator} cannot join two references"),
+                ));
+            };
             if l_bp < min_bp {
                 break;
             }
             self.lexer.next();
-            let rhs = self.parse_expression(r_bp)?;
+            let expected = match operator {
+                Op::And => "a reference",
+                Op::ChapterOf => "a verse",
+                _ => "a chapter or verse",
+            };
+            self.expect_after(span, expected)?;
+            let mut rhs = self.parse_expression(r_bp)?;
+            if matches!(operator, Op::Through | Op::Select) {
+                rhs = self.parse_chapter_change(rhs, r_bp)?;
+            }
             lhs = match operator {
                 Op::And => Node::And(Box::new(lhs), Box::new(rhs)),
                 Op::ChapterOf => {
-                    let chapter = lhs.try_into().map_err(|e| {
-                        miette::miette! {
-                            "{e}"
-                        }
-                    })?;
+                    let chapter = lhs
+                        .try_into()
+                        .map_err(|e| self.error_at(span, "this separator", e))?;
                     Node::InChapter(chapter, Box::new(rhs))
                 }
-                Op::BookOf => todo!(),
-                Op::Following => todo!(),
                 Op::Select => Node::Select(Box::new(lhs), Box::new(rhs)),
                 Op::Through => Node::Through(Box::new(lhs), Box::new(rhs)),
+                Op::BookOf | Op::Chapters | Op::Following | Op::Verses => {
+                    return Err(self.error_at(
+                        span,
+                        "this operator",
+                        format!("{operator} cannot join two references"),
+                    ));
+                }
             };
-            continue;
         }
 
         Ok(lhs)
@@ -120,7 +398,16 @@ impl<'de> Parser<'de> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{bvc::Book, parser::Parser, parser::token_tree::Node};
+    use proptest::prelude::*;
+
+    use crate::{
+        bvc::{Book, ChapterNumber, VersePartLabel},
+        canon::{OrthodoxCanon, ProtestantCanon},
+        parser::Parser,
+        parser::options::{ParseOptions, SeparatorConvention},
+        parser::token_tree::Node,
+        scripture_ref_builder::{FollowingVerses, ScripturePassageRef, ScriptureRef},
+    };
 
     #[test]
     fn parse_book_of_operator() {
@@ -131,11 +418,24 @@ mod tests {
                 "with named with a numbered prefix",
             ),
             ("Psalms", Book::Psalms, "with a single word name"),
-            // (
-            //     "song of solomon",
-            //     Book::SongOfSolomon,
-            //     "with a multiple word name",
-            // ),
+            ("II Kings", Book::SecondKings, "with a roman numeral prefix"),
+            (
+                "1Kgs",
+                Book::FirstKings,
+                "with an attached abbreviated prefix",
+            ),
+            ("1st John", Book::FirstJohn, "with an ordinal number prefix"),
+            ("Third John", Book::ThirdJohn, "with an ordinal word prefix"),
+            (
+                "song of solomon",
+                Book::SongOfSongs,
+                "with a multiple word name",
+            ),
+            (
+                "Song of Songs",
+                Book::SongOfSongs,
+                "with the full multiple word name",
+            ),
         ];
         for (reference, book, case_desc) in test_cases {
             let mut parser = Parser::new(reference);
@@ -146,17 +446,535 @@ mod tests {
         }
     }
 
-    // #[test]
-    // fn parses_following() {
-    //     let mut parser = Parser::new("8ff");
-    //     let parsed = parser.parse().expect("should have parsed");
-    //     assert_eq!("(following 8 nil)", format!("{}", parsed));
-    // }
-    //
-    // #[test]
-    // fn following_has_higher_power_than_and() {
-    //     let mut parser = Parser::new("1;8ff");
-    //     let parsed = parser.parse().expect("should have parsed");
-    //     assert_eq!("(and 1 (following 8 nil)", format!("{}", parsed));
-    // }
+    #[test]
+    fn parse_book_before_a_chapter() {
+        let mut parser = Parser::new("Psalms 23");
+        let parsed = parser.parse().expect("should have parsed");
+        assert_eq!(
+            parsed,
+            Node::InBook(Book::Psalms, Box::new(Node::Number(23)))
+        );
+    }
+
+    #[test]
+    fn parse_abbreviations_with_periods() {
+        let expected = Node::InBook(
+            Book::Genesis,
+            Box::new(Node::InChapter(1, Box::new(Node::Number(1)))),
+        );
+        let european = ParseOptions::new().separators(SeparatorConvention::European);
+        for (reference, options) in [
+            ("Gen. 1:1", ParseOptions::default()),
+            ("Gen 1.1", european),
+            ("Gen. 1.1", european),
+        ] {
+            let mut parser = Parser::with_options(reference, options);
+            let parsed = parser.parse().expect("should have parsed");
+            assert_eq!(parsed, expected, "{reference}");
+        }
+    }
+
+    #[test]
+    fn parse_each_separator_convention() {
+        let expected = Node::InBook(
+            Book::John,
+            Box::new(Node::InChapter(
+                3,
+                Box::new(Node::Select(
+                    Box::new(Node::Number(16)),
+                    Box::new(Node::Number(18)),
+                )),
+            )),
+        );
+        for (reference, convention) in [
+            ("John 3:16, 18", SeparatorConvention::Us),
+            ("John 3.16, 18", SeparatorConvention::European),
+            ("John 3,16.18", SeparatorConvention::German),
+        ] {
+            let options = ParseOptions::new().separators(convention);
+            let mut parser = Parser::with_options(reference, options);
+            let parsed = parser.parse().expect("should have parsed");
+            assert_eq!(parsed, expected, "{reference}");
+        }
+    }
+
+    #[test]
+    fn parse_ranges_with_unicode_dashes() {
+        let mut parser = Parser::new("John\u{a0}3:16\u{2013}18");
+        let parsed = parser.parse().expect("should have parsed");
+        assert_eq!(
+            parsed,
+            Node::InBook(
+                Book::John,
+                Box::new(Node::InChapter(
+                    3,
+                    Box::new(Node::Through(
+                        Box::new(Node::Number(16)),
+                        Box::new(Node::Number(18)),
+                    )),
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn parse_verse_parts() {
+        let part = |letter| VersePartLabel::new(letter).unwrap();
+        let test_cases = [
+            (
+                "John 3:16a",
+                Node::InBook(
+                    Book::John,
+                    Box::new(Node::InChapter(
+                        3,
+                        Box::new(Node::VersePart(16, part(b'a'))),
+                    )),
+                ),
+            ),
+            (
+                "Rom 8:28b\u{2013}30",
+                Node::InBook(
+                    Book::Romans,
+                    Box::new(Node::InChapter(
+                        8,
+                        Box::new(Node::Through(
+                            Box::new(Node::VersePart(28, part(b'b'))),
+                            Box::new(Node::Number(30)),
+                        )),
+                    )),
+                ),
+            ),
+            (
+                "Ps 23:1a,3b",
+                Node::InBook(
+                    Book::Psalms,
+                    Box::new(Node::InChapter(
+                        23,
+                        Box::new(Node::Select(
+                            Box::new(Node::VersePart(1, part(b'a'))),
+                            Box::new(Node::VersePart(3, part(b'b'))),
+                        )),
+                    )),
+                ),
+            ),
+        ];
+        for (reference, expected) in test_cases {
+            let mut parser = Parser::new(reference);
+            let parsed = parser.parse().expect("should have parsed");
+            assert_eq!(parsed, expected, "{reference}");
+        }
+    }
+
+    #[test]
+    fn parse_validated_references() {
+        let test_cases = [
+            ("John 3:16", "John 3:16"),
+            ("John 3:16-18", "John 3:16-18"),
+            ("Rom 8:28b, 31", "Romans 8:28b; Romans 8:31"),
+            ("Ps 23; 24:1", "Psalms 23; Psalms 24:1"),
+            ("John 3:16ff", "John 3:16-36"),
+        ];
+        for (reference, expected) in test_cases {
+            let mut parser = Parser::new(reference);
+            let parsed = parser
+                .parse_reference(&ProtestantCanon)
+                .expect("should have parsed");
+            assert_eq!(parsed.to_string(), expected, "{reference}");
+        }
+    }
+
+    #[test]
+    fn parse_ranges_across_chapters_and_books() {
+        let test_cases = [
+            ("John 3:16\u{2013}4:2", "John 3:16", "John 4:2"),
+            ("Genesis 49\u{2013}Exodus 2", "Genesis 49:1", "Exodus 2:25"),
+            ("Gen 50:26\u{2013}Ex 1:5", "Genesis 50:26", "Exodus 1:5"),
+            ("Romans 8-9", "Romans 8:1", "Romans 9:33"),
+            ("John 3-4:2", "John 3:1", "John 4:2"),
+            ("Genesis\u{2013}Exodus", "Genesis 1:1", "Exodus 40:38"),
+            ("Exodus\u{2013}Leviticus 2:3", "Exodus 1:1", "Leviticus 2:3"),
+            ("Philemon\u{2013}Hebrews 2", "Philemon 1", "Hebrews 2:18"),
+            ("2 John\u{2013}Jude", "2 John 1", "Jude 25"),
+        ];
+        for (reference, start, end) in test_cases {
+            let parsed = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .expect("should have parsed");
+            let passage = match parsed {
+                ScriptureRef::Passage(passage) => passage,
+                ScriptureRef::Chapters(chapters) => ScripturePassageRef::from(chapters),
+                _ => panic!("{reference} should be a single passage, not {parsed}"),
+            };
+            assert_eq!(passage.start().to_string(), start, "{reference}");
+            assert_eq!(passage.end().to_string(), end, "{reference}");
+        }
+    }
+
+    #[test]
+    fn parse_whole_books_and_chapters() {
+        let test_cases = [
+            ("Genesis", "Genesis"),
+            ("Psalm 23", "Psalms 23"),
+            ("Romans 8\u{2013}9", "Romans 8-9"),
+            ("Genesis 49\u{2013}Exodus 2", "Genesis 49-Exodus 2"),
+            ("Ps 23; 24:1", "Psalms 23; Psalms 24:1"),
+            ("Genesis; Exodus 1", "Genesis; Exodus 1"),
+        ];
+        for (reference, expected) in test_cases {
+            let parsed = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .expect("should have parsed");
+            assert_eq!(parsed.to_string(), expected, "{reference}");
+        }
+        let genesis = Parser::new("Genesis").parse_reference(&ProtestantCanon);
+        assert!(matches!(genesis, Ok(ScriptureRef::Book(_))));
+        let romans = Parser::new("Romans 8-9").parse_reference(&ProtestantCanon);
+        assert!(matches!(romans, Ok(ScriptureRef::Chapters(_))));
+    }
+
+    #[test]
+    fn display_ranges_across_single_chapter_books_that_parse_again() {
+        let test_cases = [
+            ("Titus 3\u{2013}Philemon", "Titus 3-Philemon"),
+            ("Philemon\u{2013}Hebrews 2", "Philemon-Hebrews 2"),
+            ("Amos 9\u{2013}Obadiah", "Amos 9-Obadiah"),
+            ("Obadiah\u{2013}Jonah 2", "Obadiah-Jonah 2"),
+            ("2 John\u{2013}3 John", "2 John-3 John"),
+            ("1 John 5\u{2013}2 John", "1 John 5-2 John"),
+            ("3 John\u{2013}Jude", "3 John-Jude"),
+            ("Jude\u{2013}Revelation 1", "Jude-Revelation 1"),
+            ("Psalms 150\u{2013}Psalm 151", "Psalms 150-Psalm 151"),
+        ];
+        for (reference, expected) in test_cases {
+            let parsed = Parser::new(reference)
+                .parse_reference(&OrthodoxCanon)
+                .expect("should have parsed");
+            let displayed = parsed.to_string();
+            assert_eq!(displayed, expected, "{reference}");
+            let reparsed = Parser::new(&displayed)
+                .parse_reference(&OrthodoxCanon)
+                .expect("should have parsed again");
+            assert_eq!(reparsed.to_string(), displayed, "{reference}");
+            assert!(
+                matches!(reparsed, ScriptureRef::Chapters(_)),
+                "{displayed} should be a run of chapters, not {reparsed:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_verses_of_single_chapter_books() {
+        let test_cases = [
+            ("Jude 5", "Jude 5"),
+            ("Obadiah 1:3-4", "Obadiah 3-4"),
+            ("Phlm 10", "Philemon 10"),
+            ("2 John 12", "2 John 12"),
+            ("3 John 13f", "3 John 13-14"),
+            ("Jude 3; Rev 1:1", "Jude 3; Revelation 1:1"),
+        ];
+        for (reference, expected) in test_cases {
+            let parsed = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .expect("should have parsed");
+            assert_eq!(parsed.to_string(), expected, "{reference}");
+        }
+    }
+
+    #[test]
+    fn parse_psalm_151_apart_from_the_psalms() {
+        let test_cases = [
+            ("Psalm 151", "Psalm 151"),
+            ("Ps 151 4-5", "Psalm 151:4-5"),
+            ("Psalm 151:1", "Psalm 151:1"),
+            ("Ps 151:4-5", "Psalm 151:4-5"),
+            ("Psalm 151:4, 6", "Psalm 151:4; Psalm 151:6"),
+            ("Psalm 150; Psalm 151", "Psalms 150; Psalm 151"),
+        ];
+        for (reference, expected) in test_cases {
+            let parsed = Parser::new(reference)
+                .parse_reference(&OrthodoxCanon)
+                .expect("should have parsed");
+            assert_eq!(parsed.to_string(), expected, "{reference}");
+        }
+        assert!(
+            Parser::new("Psalm 151")
+                .parse_reference(&ProtestantCanon)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn parse_lists_that_inherit_books_and_chapters() {
+        let test_cases = [
+            (
+                "John 3:16, 18; 4:1\u{2013}3; Rom 8:28",
+                "John 3:16; John 3:18; John 4:1-3; Romans 8:28",
+            ),
+            ("John 3:16, 4:2, 5", "John 3:16; John 4:2; John 4:5"),
+            ("John 3:16-4:2, 5; 6", "John 3:16-4:2; John 4:5; John 6"),
+            (
+                "Rom 8:28; 12:1, 2; Gal 5:22",
+                "Romans 8:28; Romans 12:1; Romans 12:2; Galatians 5:22",
+            ),
+            ("Ps 23, 24", "Psalms 23; Psalms 24"),
+            ("Jude 3; 5", "Jude 3; Jude 5"),
+        ];
+        for (reference, expected) in test_cases {
+            let parsed = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .expect("should have parsed");
+            assert!(matches!(parsed, ScriptureRef::Selection(_)), "{reference}");
+            assert_eq!(parsed.to_string(), expected, "{reference}");
+        }
+        let options = ParseOptions::new().separators(SeparatorConvention::European);
+        let parsed = Parser::with_options("John 3.16, 4.2; 5.1", options)
+            .parse_reference(&ProtestantCanon)
+            .expect("should have parsed");
+        assert_eq!(parsed.to_string(), "John 3:16; John 4:2; John 5:1");
+    }
+
+    #[test]
+    fn parse_references_within_a_book_or_chapter() {
+        let john_3 = ChapterNumber::new(3).unwrap();
+        let test_cases = [
+            ("3:16", "John 3:16"),
+            ("v. 7", "John 3:7"),
+            ("vv. 4\u{2013}6", "John 3:4-6"),
+            ("ch. 5", "John 5"),
+            ("Chapter 5:1, 3", "John 5:1; John 5:3"),
+            ("7", "John 3:7"),
+            ("vv. 4-6; ch. 5", "John 3:4-6; John 5"),
+            ("Rom 8:28", "Romans 8:28"),
+        ];
+        for (reference, expected) in test_cases {
+            let parsed = Parser::new(reference)
+                .parse_reference_in(&ProtestantCanon, Book::John, Some(john_3))
+                .expect("should have parsed");
+            assert_eq!(parsed.to_string(), expected, "{reference}");
+        }
+
+        let in_john = |reference| {
+            Parser::new(reference)
+                .parse_reference_in(&ProtestantCanon, Book::John, None)
+                .map(|parsed| parsed.to_string())
+                .map_err(|e| e.to_string())
+        };
+        assert_eq!(in_john("3:16").as_deref(), Ok("John 3:16"));
+        assert_eq!(in_john("ch. 5").as_deref(), Ok("John 5"));
+        assert_eq!(in_john("5").as_deref(), Ok("John 5"));
+        assert_eq!(
+            in_john("v. 7"),
+            Err("verses 7 of John need a chapter".to_string())
+        );
+
+        let in_jude = Parser::new("v. 7").parse_reference_in(&ProtestantCanon, Book::Jude, None);
+        assert_eq!(in_jude.unwrap().to_string(), "Jude 7");
+        let in_tobit = Parser::new("1:1").parse_reference_in(&ProtestantCanon, Book::Tobit, None);
+        assert!(in_tobit.is_err());
+    }
+
+    #[test]
+    fn parse_ranges_across_chapters_in_other_conventions() {
+        let options = ParseOptions::new().separators(SeparatorConvention::German);
+        let parsed = Parser::with_options("John 3,16-4,2", options)
+            .parse_reference(&ProtestantCanon)
+            .expect("should have parsed");
+        assert_eq!(parsed.to_string(), "John 3:16-4:2");
+    }
+
+    #[test]
+    fn reject_ranges_that_end_before_they_start() {
+        for reference in ["John 4:2-3:16", "Exodus 1-Genesis 2"] {
+            let error = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .unwrap_err();
+            assert!(error.to_string().contains("comes after"), "{reference}");
+        }
+    }
+
+    #[test]
+    fn reject_references_to_verses_that_do_not_exist() {
+        for reference in ["John 3:37", "John 22:1", "Tobit 1:1"] {
+            let mut parser = Parser::new(reference);
+            assert!(
+                parser.parse_reference(&ProtestantCanon).is_err(),
+                "{reference}"
+            );
+        }
+    }
+
+    #[test]
+    fn reject_separators_of_other_conventions() {
+        let mut parser = Parser::new("John 3.16");
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn parses_following() {
+        let mut parser = Parser::new("8ff");
+        let parsed = parser.parse().expect("should have parsed");
+        assert_eq!(
+            parsed,
+            Node::Following(Box::new(Node::Number(8)), FollowingVerses::RestOfChapter)
+        );
+    }
+
+    #[test]
+    fn following_has_higher_power_than_and() {
+        let mut parser = Parser::new("1;8f");
+        let parsed = parser.parse().expect("should have parsed");
+        assert_eq!(
+            parsed,
+            Node::And(
+                Box::new(Node::Number(1)),
+                Box::new(Node::Following(
+                    Box::new(Node::Number(8)),
+                    FollowingVerses::Next
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn following_verses_are_in_their_chapter() {
+        let mut parser = Parser::new("John 3:16ff");
+        let parsed = parser.parse().expect("should have parsed");
+        assert_eq!(
+            parsed,
+            Node::InBook(
+                Book::John,
+                Box::new(Node::InChapter(
+                    3,
+                    Box::new(Node::Following(
+                        Box::new(Node::Number(16)),
+                        FollowingVerses::RestOfChapter
+                    ))
+                ))
+            )
+        );
+    }
+
+    fn has_label(error: &miette::Error) -> bool {
+        error
+            .labels()
+            .is_some_and(|mut labels| labels.next().is_some())
+    }
+
+    #[test]
+    fn following_verses_need_a_verse() {
+        let parsed = Parser::new("John 3ff").parse_reference(&ProtestantCanon);
+        assert_eq!(
+            parsed.unwrap_err().to_string(),
+            "verses following chapter 3 of John need a verse"
+        );
+    }
+
+    #[test]
+    fn following_the_last_verse_of_a_chapter_is_only_that_verse() {
+        // "ff" never runs into the next chapter, so after the last verse it adds nothing
+        for reference in ["John 21:25ff", "John 21:25f"] {
+            let parsed = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .expect("should have parsed");
+            assert_eq!(parsed.to_string(), "John 21:25", "{reference}");
+        }
+    }
+
+    #[test]
+    fn errors_are_labelled() {
+        for reference in [
+            ":3",
+            "John 3:16 Acts",
+            "Hezekiah 1",
+            "John 3:999",
+            "3:16",
+            "Rom 8:28-",
+            "John 3:16,",
+            "John 3:16;",
+            "John 3:",
+            "John 3:16-4:",
+            "John 3:16; vv.",
+        ] {
+            let error = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .unwrap_err();
+            assert!(has_label(&error), "{reference}: {error:?}");
+        }
+    }
+
+    #[test]
+    fn reject_separators_that_end_the_reference() {
+        let test_cases = [
+            ("Rom 8:28-", "expected a chapter or verse after '-'", 8),
+            ("John 3:16,", "expected a chapter or verse after ','", 9),
+            ("John 3:16; ", "expected a reference after ';'", 9),
+            ("John 3:", "expected a verse after ':'", 6),
+            ("John 3:16-4:", "expected a verse after ':'", 11),
+            ("John 3:16; vv.", "expected a number after 'vv.'", 11),
+        ];
+        for (reference, message, offset) in test_cases {
+            let error = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .unwrap_err();
+            assert_eq!(error.to_string(), message, "{reference}");
+            let label = error.labels().and_then(|mut labels| labels.next());
+            assert_eq!(label.map(|l| l.offset()), Some(offset), "{reference}");
+        }
+    }
+
+    #[test]
+    fn label_the_part_of_the_reference_that_is_wrong() {
+        let test_cases = [
+            ("John 3:37", "this number", "37"),
+            ("Rom 8:28; John 3:16b, 37a", "this number", "37a"),
+            ("John 22:1", "this chapter", "22"),
+            ("John 4:2\u{2013}3:16", "this range", "John 4:2\u{2013}3:16"),
+            ("Rom 1:1; 2:3-1", "this range", "2:3-1"),
+            ("Rom 1:1; Tobit 1:1", "this book", "Tobit"),
+            ("John 3ff", "this reference", "3"),
+        ];
+        for (reference, label, text) in test_cases {
+            let error = Parser::new(reference)
+                .parse_reference(&ProtestantCanon)
+                .unwrap_err();
+            let found = error.labels().and_then(|mut labels| labels.next());
+            let found = found.expect("should be labelled");
+            assert_eq!(found.label(), Some(label), "{reference}");
+            let span = found.offset()..found.offset() + found.len();
+            assert_eq!(&reference[span], text, "{reference}");
+        }
+    }
+
+    #[test]
+    fn reject_deeply_nested_references() {
+        let reference = "Gen ".repeat(10_000);
+        let error = Parser::new(&reference).parse().unwrap_err();
+        assert_eq!(error.to_string(), "the reference is nested too deeply");
+    }
+
+    proptest! {
+        #[test]
+        fn parsing_any_input_never_panics(input in "\\PC*") {
+            if let Err(error) = Parser::new(&input).parse() {
+                prop_assert!(has_label(&error), "{error:?}");
+            }
+        }
+
+        #[test]
+        fn parsing_reference_like_input_never_panics(
+            input in "([0-9]{1,4}|[a-dfI]|ff|Gen|John|Song of Songs|[:;,.\\-\u{2013} ]){0,24}"
+        ) {
+            for separators in [
+                SeparatorConvention::Us,
+                SeparatorConvention::European,
+                SeparatorConvention::German,
+            ] {
+                let options = ParseOptions::new().separators(separators);
+                let parsed = Parser::with_options(&input, options).parse_reference(&ProtestantCanon);
+                if let Err(error) = parsed {
+                    prop_assert!(has_label(&error), "{error:?}");
+                }
+            }
+        }
+    }
 }
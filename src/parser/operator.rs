@@ -5,9 +5,11 @@ pub enum Op {
     And,
     BookOf,
     ChapterOf,
+    Chapters,
     Following,
     Select,
     Through,
+    Verses,
 }
 
 impl fmt::Display for Op {
@@ -16,9 +18,11 @@ impl fmt::Display for Op {
             Op::And => "and",
             Op::BookOf => "book of",
             Op::ChapterOf => "chapter of",
+            Op::Chapters => "chapters",
             Op::Following => "following",
             Op::Select => "select",
             Op::Through => "through",
+            Op::Verses => "verses",
         };
         write!(f, "{}", text)
     }
@@ -0,0 +1,82 @@
+use std::fmt;
+
+use super::operator::Op;
+use crate::lexer::Token;
+
+/// How a reference separates a chapter from its verses, and the items of a list.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum SeparatorConvention {
+    /// "John 3:16, 18", as in the US.
+    #[default]
+    Us,
+    /// "John 3.16, 18", as in the UK and OSIS.
+    European,
+    /// "John 3,16.18", as in Germany.
+    German,
+}
+
+impl SeparatorConvention {
+    /// Returns the operator the separator stands for in this convention, if any. Colons always
+    /// separate a chapter from its verses, since no convention lists items with them.
+    pub fn operator(&self, separator: Token) -> Option<Op> {
+        match (self, separator) {
+            (_, Token::Colon) => Some(Op::ChapterOf),
+            (SeparatorConvention::Us, Token::Comma) => Some(Op::Select),
+            (SeparatorConvention::European, Token::Period) => Some(Op::ChapterOf),
+            (SeparatorConvention::European, Token::Comma) => Some(Op::Select),
+            (SeparatorConvention::German, Token::Comma) => Some(Op::ChapterOf),
+            (SeparatorConvention::German, Token::Period) => Some(Op::Select),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SeparatorConvention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeparatorConvention::Us => write!(f, "US"),
+            SeparatorConvention::European => write!(f, "European"),
+            SeparatorConvention::German => write!(f, "German"),
+        }
+    }
+}
+
+/// Options that change how references are parsed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParseOptions {
+    separators: SeparatorConvention,
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn separators(mut self, separators: SeparatorConvention) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    pub fn separator_convention(&self) -> SeparatorConvention {
+        self.separators
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_convention_separates_chapters_and_lists() {
+        let test_cases = [
+            (SeparatorConvention::Us, Token::Colon, Token::Comma),
+            (SeparatorConvention::European, Token::Period, Token::Comma),
+            (SeparatorConvention::German, Token::Comma, Token::Period),
+        ];
+        for (convention, chapter, list) in test_cases {
+            assert_eq!(convention.operator(chapter), Some(Op::ChapterOf));
+            assert_eq!(convention.operator(list), Some(Op::Select));
+        }
+        assert_eq!(SeparatorConvention::Us.operator(Token::Period), None);
+    }
+}
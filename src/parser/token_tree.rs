@@ -1,29 +1,74 @@
 use std::fmt;
 
-use crate::bvc::Book;
+use crate::{
+    bvc::{Book, VersePartLabel},
+    scripture_ref_builder::FollowingVerses,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Node {
     And(Box<Node>, Box<Node>),
-    Book(Book),
     InBook(Book, Box<Node>),
     InChapter(u8, Box<Node>),
+    Chapters(Box<Node>),
+    Verses(Box<Node>),
+    Following(Box<Node>, FollowingVerses),
     Through(Box<Node>, Box<Node>),
     Select(Box<Node>, Box<Node>),
     Number(u8),
+    VersePart(u8, VersePartLabel),
     Nil,
 }
 
+impl Node {
+    /// Returns the node and the nodes within it, each before the nodes within it and in the order
+    /// they were written, without recursing, since a long list nests as deeply as it has items.
+    pub fn preorder(&self) -> impl Iterator<Item = &Node> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            match node {
+                Node::And(left, right) | Node::Through(left, right) | Node::Select(left, right) => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+                Node::InBook(_, node)
+                | Node::InChapter(_, node)
+                | Node::Chapters(node)
+                | Node::Verses(node)
+                | Node::Following(node, _) => stack.push(node),
+                Node::Number(_) | Node::VersePart(..) | Node::Nil => {}
+            }
+            Some(node)
+        })
+    }
+
+    /// Whether the node names a book or number of its own that was written before the nodes
+    /// within it, e.g. the book of "John 3" or the chapter of "3:16".
+    pub fn is_written(&self) -> bool {
+        matches!(
+            self,
+            Node::InBook(..) | Node::InChapter(..) | Node::Number(_) | Node::VersePart(..)
+        )
+    }
+}
+
 impl fmt::Display for Node {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Node::And(left, right) => write!(f, "{} and(;) {}", left, right),
-            Node::Book(book) => write!(f, "{}", book),
             Node::InBook(book, node) => write!(f, "{} in the book of {}", node, book),
             Node::InChapter(chapter, node) => write!(f, "verse {} in chapter {}", node, chapter),
+            Node::Chapters(node) => write!(f, "chapters {}", node),
+            Node::Verses(node) => write!(f, "verses {}", node),
+            Node::Following(node, FollowingVerses::Next) => write!(f, "{} and the next", node),
+            Node::Following(node, FollowingVerses::RestOfChapter) => {
+                write!(f, "{} and following", node)
+            }
             Node::Through(initial, end) => write!(f, "{} through {}", initial, end),
             Node::Select(left, right) => write!(f, "select {} and(,) {}", left, right),
             Node::Number(number) => write!(f, "{}", number),
+            Node::VersePart(number, part) => write!(f, "{}{}", number, part),
             Node::Nil => write!(f, "nil"),
         }
     }
@@ -1,55 +1,137 @@
 use crate::{
-    Book, Chapter, ChapterNumber, Verse, VerseNumber, VersePartLabel, bvc::ScripturePosition,
+    Book, Chapter, ChapterNumber, Verse, VerseNumber, VersePartLabel,
+    bvc::ScripturePosition,
+    canon::{Canonical, InCanon},
+    scripture_span::{self, ScriptureEnd, ScriptureSpan},
+    versification::Versification,
 };
 
 #[derive(Debug, Clone)]
-pub(crate) enum SelectionPart {
+pub enum SelectionPart {
+    Book(ScriptureBookRef),
+    Chapters(ScriptureChapterRef),
     Verse(ScriptureVerseRef),
     Passage(ScripturePassageRef),
 }
 
 // TODO: import instead
 #[derive(Debug)]
-pub(crate) enum ScriptureRef {
+pub enum ScriptureRef {
+    Book(ScriptureBookRef),
+    Chapters(ScriptureChapterRef),
     Verse(ScriptureVerseRef),
     Passage(ScripturePassageRef),
     Selection(ScriptureSelectionRef),
 }
 
+/// A whole book, e.g. "Genesis".
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub(crate) struct ScriptureVerseRef {
+pub struct ScriptureBookRef(Book);
+
+/// Whole chapters, e.g. "Psalm 23" or "Romans 8-9".
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ScriptureChapterRef {
+    start: Chapter,
+    end: Chapter,
+}
+
+/// A verse, e.g. "John 3:16". The verse is kept in the English versification, like the chapters
+/// and verses of `Book`, and numbered in the versification of the reference when it is read or
+/// displayed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ScriptureVerseRef {
     verse: Verse,
     verse_part: Option<VersePartLabel>,
+    versification: Versification,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct ScripturePassageRef {
+pub struct ScripturePassageRef {
     start: ScriptureVerseRef,
     end: ScriptureVerseRef,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct ScriptureSelectionRef(Vec<SelectionPart>);
+pub struct ScriptureSelectionRef(Vec<SelectionPart>);
+
+/// The verses after a verse that "f" and "ff" refer to, e.g. "John 3:16ff".
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FollowingVerses {
+    /// "f", the verse after.
+    Next,
+    /// "ff", the rest of the chapter.
+    RestOfChapter,
+}
 
 #[derive(Debug)]
-pub(crate) struct ScriptureVerseRefBuilder {
+pub struct ScriptureVerseRefBuilder {
     book: Option<Book>,
     chapter: Option<ChapterNumber>,
     verse: Option<VerseNumber>,
     verse_part: Option<VersePartLabel>,
+    versification: Versification,
 }
 
 #[derive(Debug)]
-pub(crate) struct ScripturePassageRefBuilder {
+pub struct ScripturePassageRefBuilder {
     start: Option<ScriptureVerseRef>,
     end: Option<ScriptureVerseRef>,
 }
 
 #[derive(Debug)]
-pub(crate) struct ScriptureSelectionRefBuilder {
+pub struct ScriptureSelectionRefBuilder {
     selection: Vec<SelectionPart>,
 }
 
+impl ScriptureBookRef {
+    pub fn new(book: Book) -> Self {
+        Self(book)
+    }
+
+    pub fn book(&self) -> Book {
+        self.0
+    }
+
+    /// The span of the book in the canon.
+    pub fn span<C: Canonical + ?Sized>(
+        &self,
+        canon: &C,
+    ) -> Result<(scripture_span::ScripturePosition, ScriptureEnd), String> {
+        let book = InCanon::new(self.0, canon);
+        Ok((book.start_position()?, book.end_position()?))
+    }
+}
+
+impl ScriptureChapterRef {
+    /// Creates a run of chapters, which must not end before it starts. Runs across books can only
+    /// be ordered within a canon, so they are checked when they are parsed.
+    pub fn new(start: Chapter, end: Chapter) -> Result<Self, String> {
+        if start.book == end.book && start.number.get() > end.number.get() {
+            return Err(format!("{start} comes after {end}"));
+        }
+        Ok(Self { start, end })
+    }
+
+    pub fn start(&self) -> Chapter {
+        self.start
+    }
+
+    pub fn end(&self) -> Chapter {
+        self.end
+    }
+
+    /// The span of the chapters in the canon, from the start of the first to the end of the last.
+    pub fn span<C: Canonical + ?Sized>(
+        &self,
+        canon: &C,
+    ) -> Result<(scripture_span::ScripturePosition, ScriptureEnd), String> {
+        Ok((
+            InCanon::new(self.start, canon).start_position()?,
+            InCanon::new(self.end, canon).end_position()?,
+        ))
+    }
+}
+
 impl ScriptureVerseRef {
     pub fn new(
         book: Book,
@@ -60,6 +142,53 @@ impl ScriptureVerseRef {
         Ok(Self {
             verse: Verse::new(book, chapter, verse)?,
             verse_part,
+            versification: Versification::English,
+        })
+    }
+
+    /// Creates a reference numbered in the given versification, e.g. Malachi 3:19 in the
+    /// Hebrew text.
+    pub fn new_in(
+        book: Book,
+        chapter: ChapterNumber,
+        verse: VerseNumber,
+        verse_part: Option<VersePartLabel>,
+        versification: Versification,
+    ) -> Result<Self, String> {
+        Ok(Self {
+            verse: versification.english_equivalent(book, chapter, verse)?,
+            verse_part,
+            versification,
+        })
+    }
+
+    /// The verse as its versification numbers it, e.g. Malachi 3:19 in the Hebrew text.
+    pub fn verse(&self) -> Verse {
+        self.versification
+            .number(&self.verse)
+            .expect("every English verse is numbered in every versification")
+    }
+
+    /// The equivalent verse in the English versification, e.g. Malachi 4:1 for Malachi 3:19 in
+    /// the Hebrew text.
+    pub fn english_verse(&self) -> Verse {
+        self.verse
+    }
+
+    pub fn verse_part(&self) -> Option<VersePartLabel> {
+        self.verse_part
+    }
+
+    pub fn versification(&self) -> Versification {
+        self.versification
+    }
+
+    /// Converts the reference to the equivalent verse in another versification.
+    pub fn to_versification(self, versification: Versification) -> Result<Self, String> {
+        versification.number(&self.verse)?;
+        Ok(Self {
+            versification,
+            ..self
         })
     }
 
@@ -75,6 +204,7 @@ impl ScriptureVerseRefBuilder {
             chapter: None,
             verse: None,
             verse_part: None,
+            versification: Versification::English,
         }
     }
 
@@ -114,6 +244,23 @@ impl ScriptureVerseRefBuilder {
         Ok(self.verse(verse.try_into()?))
     }
 
+    pub fn verse_part(mut self, verse_part: VersePartLabel) -> Self {
+        self.verse_part = Some(verse_part);
+        self
+    }
+
+    pub fn try_verse_part<T>(self, verse_part: T) -> Result<Self, T::Error>
+    where
+        T: TryInto<VersePartLabel>,
+    {
+        Ok(self.verse_part(verse_part.try_into()?))
+    }
+
+    pub fn versification(mut self, versification: Versification) -> Self {
+        self.versification = versification;
+        self
+    }
+
     pub fn build(&self) -> Result<ScriptureVerseRef, String> {
         let book = self.book.ok_or_else(|| "book is required".to_string())?;
         let chapter = self
@@ -121,15 +268,54 @@ impl ScriptureVerseRefBuilder {
             .ok_or_else(|| "chapter is required".to_string())?;
         let verse = self.verse.ok_or_else(|| "verse is required".to_string())?;
 
-        ScriptureVerseRef::new(book, chapter, verse, self.verse_part)
+        ScriptureVerseRef::new_in(book, chapter, verse, self.verse_part, self.versification)
     }
 }
 
 impl ScripturePassageRef {
+    /// Creates a passage, which must not end before it starts. Passages across books can only be
+    /// ordered within a canon, so they are checked when they are parsed.
     pub fn new(start: ScriptureVerseRef, end: ScriptureVerseRef) -> Result<Self, String> {
+        // the ends may be numbered differently, so they are compared by their English numbers
+        let position = |verse: ScriptureVerseRef| {
+            let (part, verse) = (verse.verse_part, verse.verse);
+            ScripturePosition::new(verse.book, verse.chapter.number, verse.number, part).get()
+        };
+        if start.verse.book == end.verse.book && position(start) > position(end) {
+            return Err(format!("{start} comes after {end}"));
+        }
         Ok(Self { start, end })
     }
 
+    /// Creates the passage from a verse through the verses following it, which never extends
+    /// past the end of the chapter as its versification numbers it.
+    pub fn following(start: ScriptureVerseRef, following: FollowingVerses) -> Result<Self, String> {
+        let verse = start.verse();
+        let last_verse = start
+            .versification()
+            .max_verse_count(verse.book, verse.chapter.number)?;
+        let end = match following {
+            FollowingVerses::Next => verse.number.get().saturating_add(1).min(last_verse),
+            FollowingVerses::RestOfChapter => last_verse,
+        };
+        let end = ScriptureVerseRef::new_in(
+            verse.book,
+            verse.chapter.number,
+            VerseNumber::new(end)?,
+            None,
+            start.versification(),
+        )?;
+        Self::new(start, end)
+    }
+
+    pub fn start(&self) -> ScriptureVerseRef {
+        self.start
+    }
+
+    pub fn end(&self) -> ScriptureVerseRef {
+        self.end
+    }
+
     pub fn builder() -> ScripturePassageRefBuilder {
         ScripturePassageRefBuilder::new()
     }
@@ -154,7 +340,6 @@ impl ScripturePassageRefBuilder {
     }
 
     pub fn build(&self) -> Result<ScripturePassageRef, String> {
-        // TODO: should I handle misorderd or unordered verse refs?
         let start = self
             .start
             .ok_or_else(|| "starting verse ref is required".to_string())?;
@@ -202,35 +387,91 @@ impl ScriptureSelectionRefBuilder {
     }
 }
 
+impl Default for ScriptureVerseRefBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for ScripturePassageRefBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for ScriptureSelectionRefBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for ScriptureBookRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::fmt::Display for ScriptureChapterRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (start, end) = (self.start, self.end);
+        if start == end && start.book.has_one_chapter() {
+            write!(f, "{}", start.book)
+        } else if start == end {
+            write!(f, "{} {}", start.book, start.number)
+        } else if start.book == end.book {
+            write!(f, "{} {}-{}", start.book, start.number, end.number)
+        } else {
+            // single-chapter books are named without their chapter, e.g. "Titus 3-Philemon"
+            let chapter = |chapter: Chapter| {
+                if chapter.book.has_one_chapter() {
+                    chapter.book.to_string()
+                } else {
+                    chapter.to_string()
+                }
+            };
+            write!(f, "{}-{}", chapter(start), chapter(end))
+        }
+    }
+}
+
 impl std::fmt::Display for ScriptureVerseRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(part) = &self.verse_part {
-            write!(f, "{}{part}", self.verse)
+            write!(f, "{}{part}", self.verse())
         } else {
-            write!(f, "{}", self.verse)
+            write!(f, "{}", self.verse())
         }
     }
 }
 
 impl std::fmt::Display for ScripturePassageRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // TODO: optimize, probably done using ref ids
-        if self.start.verse == self.end.verse {
+        let (start, end) = (self.start.verse(), self.end.verse());
+        let end_part = self
+            .end
+            .verse_part
+            .map(|p| p.to_string())
+            .unwrap_or_default();
+        let whole_chapter = self.start.verse_part.is_none()
+            && self.end.verse_part.is_none()
+            && start.number == VerseNumber::default()
+            && self.start.versification == self.end.versification
+            && self
+                .start
+                .versification
+                .max_verse_count(start.book, start.chapter.number)
+                == Ok(end.number.get());
+        if start == end && self.start.verse_part == self.end.verse_part {
             write!(f, "{}", self.start)
-        } else if self.start.verse.chapter == self.end.verse.chapter {
+        } else if start.chapter == end.chapter && whole_chapter {
+            write!(f, "{}", ScriptureChapterRef::from(start.chapter))
+        } else if start.chapter == end.chapter {
+            write!(f, "{}-{}{end_part}", self.start, end.number)
+        } else if start.book == end.book {
             write!(
                 f,
-                "{} {}",
-                self.start.verse.book, self.start.verse.chapter.number
-            )
-        } else if self.start.verse.book == self.end.verse.book {
-            write!(
-                f,
-                "{} {}:{}-{}",
-                self.start.verse.book,
-                self.start.verse.chapter,
-                self.start.verse.number,
-                self.end.verse.number
+                "{}-{}:{}{end_part}",
+                self.start, end.chapter.number, end.number
             )
         } else {
             write!(f, "{}-{}", self.start, self.end)
@@ -241,6 +482,8 @@ impl std::fmt::Display for ScripturePassageRef {
 impl std::fmt::Display for SelectionPart {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            SelectionPart::Book(b) => write!(f, "{}", b),
+            SelectionPart::Chapters(c) => write!(f, "{}", c),
             SelectionPart::Verse(v) => write!(f, "{}", v),
             SelectionPart::Passage(p) => write!(f, "{}", p),
         }
@@ -270,6 +513,8 @@ impl std::fmt::Display for ScriptureSelectionRef {
 impl std::fmt::Display for ScriptureRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            ScriptureRef::Book(b) => write!(f, "{}", b),
+            ScriptureRef::Chapters(c) => write!(f, "{}", c),
             ScriptureRef::Verse(v) => write!(f, "{}", v),
             ScriptureRef::Passage(p) => write!(f, "{}", p),
             ScriptureRef::Selection(s) => write!(f, "{}", s),
@@ -283,6 +528,27 @@ impl std::fmt::Display for ScripturePosition {
     }
 }
 
+impl From<ScriptureBookRef> for ScriptureRef {
+    fn from(value: ScriptureBookRef) -> Self {
+        Self::Book(value)
+    }
+}
+
+impl From<ScriptureChapterRef> for ScriptureRef {
+    fn from(value: ScriptureChapterRef) -> Self {
+        Self::Chapters(value)
+    }
+}
+
+impl From<Chapter> for ScriptureChapterRef {
+    fn from(chapter: Chapter) -> Self {
+        Self {
+            start: chapter,
+            end: chapter,
+        }
+    }
+}
+
 impl From<ScriptureVerseRef> for ScriptureRef {
     fn from(value: ScriptureVerseRef) -> Self {
         Self::Verse(value)
@@ -319,8 +585,267 @@ impl From<Chapter> for ScripturePassageRef {
     }
 }
 
+impl From<ScriptureChapterRef> for ScripturePassageRef {
+    fn from(chapters: ScriptureChapterRef) -> Self {
+        Self {
+            start: ScripturePassageRef::from(chapters.start).start,
+            end: ScripturePassageRef::from(chapters.end).end,
+        }
+    }
+}
+
+impl From<ScriptureBookRef> for ScriptureChapterRef {
+    fn from(book: ScriptureBookRef) -> Self {
+        let chapter = |number| Chapter {
+            book: book.0,
+            number: ChapterNumber::new(number).unwrap(),
+        };
+        Self {
+            start: chapter(1),
+            end: chapter(book.0.chapter_count()),
+        }
+    }
+}
+
 impl From<ScriptureSelectionRef> for ScriptureRef {
     fn from(value: ScriptureSelectionRef) -> Self {
         Self::Selection(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verse_ref(book: Book, chapter: u8, verse: u8) -> ScriptureVerseRef {
+        ScriptureVerseRef::builder()
+            .book(book)
+            .try_chapter(chapter)
+            .unwrap()
+            .try_verse(verse)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn verse_parts_are_displayed_after_the_verse() {
+        let verse = ScriptureVerseRef::builder()
+            .book(Book::John)
+            .try_chapter(3)
+            .unwrap()
+            .try_verse(16)
+            .unwrap()
+            .try_verse_part('a')
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(verse.verse_part(), Some(VersePartLabel::new(b'a').unwrap()));
+        assert_eq!(verse.to_string(), "John 3:16a");
+    }
+
+    #[test]
+    fn passages_are_displayed_in_short_form() {
+        let passage = |start, end| ScripturePassageRef::new(start, end).unwrap().to_string();
+        let test_cases = [
+            (
+                verse_ref(Book::John, 3, 1),
+                verse_ref(Book::John, 3, 36),
+                "John 3",
+            ),
+            (
+                verse_ref(Book::John, 3, 16),
+                verse_ref(Book::John, 3, 18),
+                "John 3:16-18",
+            ),
+            (
+                verse_ref(Book::John, 3, 16),
+                verse_ref(Book::John, 4, 2),
+                "John 3:16-4:2",
+            ),
+            (
+                verse_ref(Book::Genesis, 50, 26),
+                verse_ref(Book::Exodus, 1, 5),
+                "Genesis 50:26-Exodus 1:5",
+            ),
+            (
+                verse_ref(Book::Jude, 1, 5),
+                verse_ref(Book::Jude, 1, 7),
+                "Jude 5-7",
+            ),
+            (
+                verse_ref(Book::Jude, 1, 1),
+                verse_ref(Book::Jude, 1, 25),
+                "Jude",
+            ),
+            (
+                verse_ref(Book::Jude, 1, 24),
+                verse_ref(Book::Revelation, 1, 3),
+                "Jude 24-Revelation 1:3",
+            ),
+        ];
+        for (start, end, expected) in test_cases {
+            assert_eq!(passage(start, end), expected);
+        }
+    }
+
+    #[test]
+    fn whole_books_and_chapters_are_displayed_in_short_form() {
+        let chapter =
+            |book, chapter| Chapter::new(book, ChapterNumber::new(chapter).unwrap()).unwrap();
+        let chapters = |start, end| ScriptureChapterRef::new(start, end).unwrap().to_string();
+        assert_eq!(ScriptureBookRef::new(Book::Genesis).to_string(), "Genesis");
+        assert_eq!(
+            chapters(chapter(Book::Psalms, 23), chapter(Book::Psalms, 23)),
+            "Psalms 23"
+        );
+        assert_eq!(
+            chapters(chapter(Book::Philemon, 1), chapter(Book::Philemon, 1)),
+            "Philemon"
+        );
+        assert_eq!(
+            chapters(chapter(Book::Romans, 8), chapter(Book::Romans, 9)),
+            "Romans 8-9"
+        );
+        assert_eq!(
+            chapters(chapter(Book::Genesis, 49), chapter(Book::Exodus, 2)),
+            "Genesis 49-Exodus 2"
+        );
+        assert!(
+            ScriptureChapterRef::new(chapter(Book::Romans, 9), chapter(Book::Romans, 8)).is_err()
+        );
+    }
+
+    #[test]
+    fn whole_books_and_chapters_span_the_canon() {
+        use crate::canon::ProtestantCanon;
+
+        let chapter =
+            |book, chapter| Chapter::new(book, ChapterNumber::new(chapter).unwrap()).unwrap();
+        let (exodus, exodus_end) = ScriptureBookRef::new(Book::Exodus)
+            .span(&ProtestantCanon)
+            .unwrap();
+        let (genesis, genesis_end) =
+            ScriptureChapterRef::new(chapter(Book::Genesis, 1), chapter(Book::Genesis, 50))
+                .unwrap()
+                .span(&ProtestantCanon)
+                .unwrap();
+        let (leviticus, _) = ScriptureBookRef::new(Book::Leviticus)
+            .span(&ProtestantCanon)
+            .unwrap();
+        assert!(genesis < exodus);
+        assert_eq!(genesis_end.get(), exodus.get());
+        assert_eq!(exodus_end.get(), leviticus.get());
+        assert!(
+            ScriptureBookRef::new(Book::Tobit)
+                .span(&ProtestantCanon)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn passages_cannot_end_before_they_start() {
+        let passage = ScripturePassageRef::builder()
+            .start_at(verse_ref(Book::John, 4, 2))
+            .end_at(verse_ref(Book::John, 3, 16))
+            .build();
+        assert_eq!(
+            passage.map(|p| p.to_string()),
+            Err("John 4:2 comes after John 3:16".to_string())
+        );
+    }
+
+    #[test]
+    fn following_verses_end_within_the_chapter() {
+        let test_cases = [
+            (16, FollowingVerses::Next, 17),
+            (16, FollowingVerses::RestOfChapter, 36),
+            (36, FollowingVerses::Next, 36),
+        ];
+        for (verse, following, end) in test_cases {
+            let passage =
+                ScripturePassageRef::following(verse_ref(Book::John, 3, verse), following).unwrap();
+            assert_eq!(passage.start(), verse_ref(Book::John, 3, verse));
+            assert_eq!(passage.end(), verse_ref(Book::John, 3, end));
+        }
+    }
+
+    #[test]
+    fn following_verses_end_within_the_chapter_of_the_versification() {
+        let hebrew = |chapter, verse| {
+            ScriptureVerseRef::builder()
+                .book(Book::Malachi)
+                .try_chapter(chapter)
+                .unwrap()
+                .try_verse(verse)
+                .unwrap()
+                .versification(Versification::Masoretic)
+                .build()
+                .unwrap()
+        };
+        let test_cases = [
+            ((3, 19), FollowingVerses::RestOfChapter, (3, 24)),
+            ((3, 19), FollowingVerses::Next, (3, 20)),
+            ((3, 24), FollowingVerses::Next, (3, 24)),
+            ((3, 10), FollowingVerses::RestOfChapter, (3, 24)),
+        ];
+        for ((chapter, verse), following, (end_chapter, end_verse)) in test_cases {
+            let passage =
+                ScripturePassageRef::following(hebrew(chapter, verse), following).unwrap();
+            assert_eq!(passage.end(), hebrew(end_chapter, end_verse));
+        }
+    }
+
+    #[test]
+    fn verses_are_kept_in_the_english_versification() {
+        use crate::canon::ProtestantCanon;
+
+        let hebrew = ScriptureVerseRef::new_in(
+            Book::Malachi,
+            ChapterNumber::new(3).unwrap(),
+            VerseNumber::new(19).unwrap(),
+            None,
+            Versification::Masoretic,
+        )
+        .unwrap();
+        let english = verse_ref(Book::Malachi, 4, 1);
+        assert_eq!(hebrew.to_string(), "Malachi 3:19");
+        assert_eq!(hebrew.verse().to_string(), "Malachi 3:19");
+        assert_eq!(hebrew.english_verse(), english.verse());
+        assert_eq!(hebrew.to_versification(Versification::English), Ok(english));
+        let position = |verse| InCanon::new(verse, &ProtestantCanon).start_position();
+        assert_eq!(
+            position(hebrew.english_verse()),
+            position(english.english_verse())
+        );
+    }
+
+    #[test]
+    fn passages_are_ordered_across_versifications() {
+        let hebrew = ScriptureVerseRef::new_in(
+            Book::Malachi,
+            ChapterNumber::new(3).unwrap(),
+            VerseNumber::new(19).unwrap(),
+            None,
+            Versification::Masoretic,
+        )
+        .unwrap();
+        assert!(ScripturePassageRef::new(verse_ref(Book::Malachi, 3, 18), hebrew).is_ok());
+        assert!(ScripturePassageRef::new(hebrew, verse_ref(Book::Malachi, 4, 6)).is_ok());
+        assert!(ScripturePassageRef::new(verse_ref(Book::Malachi, 4, 2), hebrew).is_err());
+        // Malachi 3 has 24 verses in the Hebrew text, so its first 18 are not the whole chapter
+        let hebrew_verse = |verse| {
+            ScriptureVerseRef::new_in(
+                Book::Malachi,
+                ChapterNumber::new(3).unwrap(),
+                VerseNumber::new(verse).unwrap(),
+                None,
+                Versification::Masoretic,
+            )
+            .unwrap()
+        };
+        let passage = |start, end| ScripturePassageRef::new(start, end).unwrap().to_string();
+        assert_eq!(passage(hebrew_verse(1), hebrew_verse(18)), "Malachi 3:1-18");
+        assert_eq!(passage(hebrew_verse(1), hebrew_verse(24)), "Malachi 3");
+    }
+}
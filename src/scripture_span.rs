@@ -3,11 +3,11 @@ use crate::{
     canon::{Canonical, InCanon},
 };
 
-#[derive(Debug)]
-pub(crate) struct ScripturePosition(u32);
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct ScripturePosition(u32);
 
 #[derive(Debug)]
-pub(crate) enum ScriptureEnd {
+pub enum ScriptureEnd {
     /// Points to a real position (the start of the next verse, chapter, or book)
     NextPosition(ScripturePosition),
 
@@ -16,41 +16,34 @@ pub(crate) enum ScriptureEnd {
 }
 
 impl ScripturePosition {
-    pub(crate) fn new(book_pos: u8, chapter: u8, verse: u8, part: u8) -> Self {
+    pub fn new(book_pos: u8, chapter: u8, verse: u8, part: u8) -> Self {
         let position =
             (book_pos as u32) << 24 | (chapter as u32) << 16 | (verse as u32) << 8 | (part as u32);
         Self(position)
     }
 
-    pub(crate) fn get(&self) -> u32 {
+    pub fn get(&self) -> u32 {
         self.0
     }
 }
 
 impl ScriptureEnd {
-    pub(crate) fn get(&self) -> u32 {
+    pub fn get(&self) -> u32 {
         match self {
             ScriptureEnd::NextPosition(pos) => pos.0,
             ScriptureEnd::VirtualBoundary(pos) => *pos,
         }
     }
-
-    pub(crate) fn into_real_position(self) -> Option<ScripturePosition> {
-        match self {
-            ScriptureEnd::NextPosition(pos) => Some(pos),
-            ScriptureEnd::VirtualBoundary(_) => None,
-        }
-    }
 }
 
-pub(crate) trait ScriptureSpan {
+pub trait ScriptureSpan {
     type Parent: ScriptureSpan;
     fn start_position(&self) -> Result<ScripturePosition, String>;
     fn end_position(&self) -> Result<ScriptureEnd, String>;
     fn to_parent(&self) -> Self::Parent;
 }
 
-impl<'c, C: Canonical> ScriptureSpan for &'c C {
+impl<C: Canonical + ?Sized> ScriptureSpan for &C {
     type Parent = Self;
 
     fn start_position(&self) -> Result<ScripturePosition, String> {
@@ -66,7 +59,7 @@ impl<'c, C: Canonical> ScriptureSpan for &'c C {
     }
 }
 
-impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, Book, C> {
+impl<'c, C: Canonical + ?Sized> ScriptureSpan for InCanon<'c, Book, C> {
     type Parent = &'c C;
 
     fn start_position(&self) -> Result<ScripturePosition, String> {
@@ -88,7 +81,7 @@ impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, Book, C> {
     }
 }
 
-impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, Chapter, C> {
+impl<'c, C: Canonical + ?Sized> ScriptureSpan for InCanon<'c, Chapter, C> {
     type Parent = InCanon<'c, Book, C>;
 
     fn start_position(&self) -> Result<ScripturePosition, String> {
@@ -119,7 +112,7 @@ impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, Chapter, C> {
     }
 }
 
-impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, Verse, C> {
+impl<'c, C: Canonical + ?Sized> ScriptureSpan for InCanon<'c, Verse, C> {
     type Parent = InCanon<'c, Chapter, C>;
 
     fn start_position(&self) -> Result<ScripturePosition, String> {
@@ -132,9 +125,8 @@ impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, Verse, C> {
     }
 
     fn end_position(&self) -> Result<ScriptureEnd, String> {
-        // TODO: Should manuscript differences be handled here?
-        // From a span perspective, I doubt the exceptions are relevant.
-        // This may be a different trait, so that library implementors can specify additional exceptions.
+        // Spans are positioned in the English versification, which references numbered in
+        // another scheme keep their verses in (see `ScriptureVerseRef::english_verse`).
         let max_verse = self
             .inner
             .book
@@ -156,7 +148,7 @@ impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, Verse, C> {
     }
 }
 
-impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, VersePart, C> {
+impl<'c, C: Canonical + ?Sized> ScriptureSpan for InCanon<'c, VersePart, C> {
     type Parent = InCanon<'c, Verse, C>;
 
     fn start_position(&self) -> Result<ScripturePosition, String> {
@@ -188,8 +180,8 @@ impl<'c, C: Canonical> ScriptureSpan for InCanon<'c, VersePart, C> {
     }
 }
 
-impl<'c, T: HasBook, C: Canonical> InCanon<'c, T, C> {
-    pub(crate) fn book_pos(&self) -> Result<u8, String> {
+impl<'c, T: HasBook, C: Canonical + ?Sized> InCanon<'c, T, C> {
+    pub fn book_pos(&self) -> Result<u8, String> {
         let book = self.inner.book();
         let pos = self
             .canon
@@ -199,7 +191,7 @@ impl<'c, T: HasBook, C: Canonical> InCanon<'c, T, C> {
     }
 }
 
-impl<'c, C: Canonical> Iterator for InCanon<'c, Book, C> {
+impl<'c, C: Canonical + ?Sized> Iterator for InCanon<'c, Book, C> {
     type Item = Option<Book>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -0,0 +1,223 @@
+use crate::{
+    Book, ChapterNumber, Verse, VerseNumber,
+    bvc::ScripturePosition,
+    scripture_ref_builder::{
+        ScriptureChapterRef, ScripturePassageRef, ScriptureRef, ScriptureVerseRef, SelectionPart,
+    },
+    versification::Versification,
+};
+
+/// An English translation of the Bible.
+///
+/// Translations share the English versification, but modern translations based on the critical
+/// Greek text leave some verses of the Textus Receptus out of the text, keeping their numbers
+/// (e.g., the NIV goes from Matthew 17:20 to 17:22).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Translation {
+    /// King James Version
+    Kjv,
+    /// New King James Version
+    Nkjv,
+    /// New International Version
+    Niv,
+    /// English Standard Version
+    Esv,
+    /// New Revised Standard Version
+    Nrsv,
+}
+
+/// Verses of the King James Version that are only found in the footnotes of translations based
+/// on the critical Greek text.
+const CRITICAL_TEXT_OMISSIONS: &[(Book, u8, u8)] = &[
+    (Book::Matthew, 17, 21),
+    (Book::Matthew, 18, 11),
+    (Book::Matthew, 23, 14),
+    (Book::Mark, 7, 16),
+    (Book::Mark, 9, 44),
+    (Book::Mark, 9, 46),
+    (Book::Mark, 11, 26),
+    (Book::Mark, 15, 28),
+    (Book::Luke, 17, 36),
+    (Book::Luke, 23, 17),
+    (Book::John, 5, 4),
+    (Book::Acts, 8, 37),
+    (Book::Acts, 15, 34),
+    (Book::Acts, 24, 7),
+    (Book::Acts, 28, 29),
+    (Book::Romans, 16, 24),
+];
+
+/// A reference that is valid in the English versification, but points at a verse the
+/// translation leaves out of its text.
+#[derive(thiserror::Error, miette::Diagnostic, Debug, Clone, Copy, Eq, PartialEq)]
+#[error("{verse} is omitted from the {translation}")]
+#[diagnostic(
+    code(scripture_ref::omitted_verse),
+    severity(Warning),
+    help("the {translation} keeps the verse number, but only includes the text in a footnote")
+)]
+pub struct OmittedVerse {
+    pub(crate) verse: Verse,
+    pub(crate) translation: Translation,
+}
+
+impl Translation {
+    /// The verses of the English versification that this translation leaves out of its text.
+    fn omissions(&self) -> &'static [(Book, u8, u8)] {
+        match self {
+            Translation::Kjv | Translation::Nkjv => &[],
+            Translation::Niv | Translation::Esv | Translation::Nrsv => CRITICAL_TEXT_OMISSIONS,
+        }
+    }
+
+    fn omitted_verses(&self) -> impl Iterator<Item = Result<Verse, String>> {
+        self.omissions().iter().map(|&(book, chapter, verse)| {
+            Verse::new(book, ChapterNumber::new(chapter)?, VerseNumber::new(verse)?)
+        })
+    }
+
+    /// Whether the verse, numbered in the English versification, is left out of this
+    /// translation.
+    pub fn omits(&self, verse: &Verse) -> bool {
+        self.omitted_verses()
+            .any(|omitted| omitted.as_ref() == Ok(verse))
+    }
+
+    /// Reports every verse of the reference that is left out of this translation.
+    ///
+    /// Omitted verses are warnings rather than errors, since the reference is still valid; the
+    /// error is reserved for references that cannot be converted to the English versification.
+    pub fn check(&self, reference: &ScriptureRef) -> Result<Vec<OmittedVerse>, String> {
+        match reference {
+            ScriptureRef::Book(book) => self.check_chapters(&(*book).into()),
+            ScriptureRef::Chapters(chapters) => self.check_chapters(chapters),
+            ScriptureRef::Verse(verse) => self.check_verse(verse),
+            ScriptureRef::Passage(passage) => self.check_passage(passage),
+            ScriptureRef::Selection(selection) => {
+                let mut warnings = Vec::new();
+                for part in selection.clone().parts() {
+                    warnings.extend(match part {
+                        SelectionPart::Book(book) => self.check_chapters(&book.into())?,
+                        SelectionPart::Chapters(chapters) => self.check_chapters(&chapters)?,
+                        SelectionPart::Verse(verse) => self.check_verse(&verse)?,
+                        SelectionPart::Passage(passage) => self.check_passage(&passage)?,
+                    });
+                }
+                Ok(warnings)
+            }
+        }
+    }
+
+    fn check_verse(&self, verse: &ScriptureVerseRef) -> Result<Vec<OmittedVerse>, String> {
+        let english = verse.to_versification(Versification::English)?.verse();
+        Ok(self
+            .omits(&english)
+            .then_some(OmittedVerse {
+                verse: english,
+                translation: *self,
+            })
+            .into_iter()
+            .collect())
+    }
+
+    fn check_chapters(&self, chapters: &ScriptureChapterRef) -> Result<Vec<OmittedVerse>, String> {
+        self.check_passage(&(*chapters).into())
+    }
+
+    fn check_passage(&self, passage: &ScripturePassageRef) -> Result<Vec<OmittedVerse>, String> {
+        let passage = english_position(passage.start())?..=english_position(passage.end())?;
+        let mut warnings = Vec::new();
+        for verse in self.omitted_verses() {
+            let verse = verse?;
+            if passage.contains(&position(&verse)) {
+                warnings.push(OmittedVerse {
+                    verse,
+                    translation: *self,
+                });
+            }
+        }
+        Ok(warnings)
+    }
+}
+
+fn position(verse: &Verse) -> u32 {
+    ScripturePosition::new(verse.book, verse.chapter.number, verse.number, None).get()
+}
+
+fn english_position(verse: ScriptureVerseRef) -> Result<u32, String> {
+    Ok(position(
+        &verse.to_versification(Versification::English)?.verse(),
+    ))
+}
+
+impl std::fmt::Display for Translation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Translation::Kjv => write!(f, "KJV"),
+            Translation::Nkjv => write!(f, "NKJV"),
+            Translation::Niv => write!(f, "NIV"),
+            Translation::Esv => write!(f, "ESV"),
+            Translation::Nrsv => write!(f, "NRSV"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verse_ref(book: Book, chapter: u8, verse: u8) -> ScriptureVerseRef {
+        ScriptureVerseRef::builder()
+            .book(book)
+            .try_chapter(chapter)
+            .unwrap()
+            .try_verse(verse)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn warns_about_verses_omitted_from_modern_translations() {
+        let reference = ScriptureRef::from(verse_ref(Book::Acts, 8, 37));
+
+        assert_eq!(Translation::Kjv.check(&reference), Ok(vec![]));
+        let warnings = Translation::Niv.check(&reference).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].to_string(), "Acts 8:37 is omitted from the NIV");
+        assert_eq!(
+            miette::Diagnostic::severity(&warnings[0]),
+            Some(miette::Severity::Warning)
+        );
+    }
+
+    #[test]
+    fn does_not_warn_about_verses_in_the_text() {
+        let reference = ScriptureRef::from(verse_ref(Book::Matthew, 17, 20));
+        assert_eq!(Translation::Esv.check(&reference), Ok(vec![]));
+    }
+
+    #[test]
+    fn warns_about_each_verse_omitted_from_a_passage() {
+        let passage = ScripturePassageRef::builder()
+            .start_at(verse_ref(Book::Mark, 9, 40))
+            .end_at(verse_ref(Book::Mark, 11, 30))
+            .build()
+            .unwrap();
+
+        let omitted: Vec<String> = Translation::Nrsv
+            .check(&ScriptureRef::from(passage))
+            .unwrap()
+            .iter()
+            .map(|warning| warning.verse.to_string())
+            .collect();
+        assert_eq!(omitted, ["Mark 9:44", "Mark 9:46", "Mark 11:26"]);
+    }
+
+    #[test]
+    fn every_omitted_verse_exists_in_the_english_versification() {
+        for verse in Translation::Niv.omitted_verses() {
+            assert!(verse.is_ok(), "{verse:?}");
+        }
+    }
+}
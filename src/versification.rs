@@ -0,0 +1,475 @@
+use crate::{
+    Book, ChapterNumber, Verse, VerseNumber,
+    bvc::{Chapter, HasBook},
+};
+
+/// A scheme for numbering the chapters and verses of the Bible.
+///
+/// Traditions divide the same text differently. For example, the last six verses of Malachi are
+/// numbered 4:1-6 in English Bibles, but 3:19-24 in the Hebrew text, and the Septuagint and
+/// Vulgate number most of the Psalms one lower than the Hebrew text (Psalm 23 is Psalm 22).
+///
+/// The chapter and verse counts of `Book` follow the English versification, which is used as the
+/// pivot when converting between the other schemes.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum Versification {
+    /// The numbering of the King James Version, followed by most English translations.
+    #[default]
+    English,
+    /// The numbering of the Hebrew (Masoretic) text, as used by Jewish translations.
+    Masoretic,
+    /// The numbering of the Greek Old Testament.
+    Septuagint,
+    /// The numbering of the Latin Vulgate.
+    Vulgate,
+}
+
+/// A run of verses whose numbering differs from the English versification.
+///
+/// English chapters `chapters.0..=chapters.1` and verses `verses.0..=verses.1` start at
+/// `to.0:to.1`, so each chapter and verse keeps its offset from the start of the run.
+#[derive(Debug, Clone, Copy)]
+struct VerseMapping {
+    book: Book,
+    chapters: (u8, u8),
+    verses: (u8, u8),
+    to: (u8, u8),
+}
+
+const ALL_VERSES: (u8, u8) = (1, 176);
+
+const fn shift(book: Book, chapter: u8, verses: (u8, u8), to: (u8, u8)) -> VerseMapping {
+    VerseMapping {
+        book,
+        chapters: (chapter, chapter),
+        verses,
+        to,
+    }
+}
+
+const fn shift_chapters(book: Book, chapters: (u8, u8), to: (u8, u8)) -> VerseMapping {
+    VerseMapping {
+        book,
+        chapters,
+        verses: ALL_VERSES,
+        to,
+    }
+}
+
+/// Psalms whose title is numbered as a verse of its own in the Hebrew text.
+const fn titled_psalms(chapters: (u8, u8), title_verses: u8) -> VerseMapping {
+    shift_chapters(Book::Psalms, chapters, (chapters.0, 1 + title_verses))
+}
+
+/// Chapter divisions of the Hebrew text that differ from the English versification. Verses
+/// split across two Hebrew verses map to the first.
+///
+/// When several runs map to the same verse, the first one is used when converting back to the
+/// English versification.
+const HEBREW_CHAPTERS: &[VerseMapping] = &[
+    shift(Book::Genesis, 31, (55, 55), (32, 1)),
+    shift(Book::Genesis, 32, (1, 32), (32, 2)),
+    shift(Book::Exodus, 8, (1, 4), (7, 26)),
+    shift(Book::Exodus, 8, (5, 32), (8, 1)),
+    shift(Book::Exodus, 22, (1, 1), (21, 37)),
+    shift(Book::Exodus, 22, (2, 31), (22, 1)),
+    shift(Book::Leviticus, 6, (1, 7), (5, 20)),
+    shift(Book::Leviticus, 6, (8, 30), (6, 1)),
+    shift(Book::Numbers, 16, (36, 50), (17, 1)),
+    shift(Book::Numbers, 17, (1, 13), (17, 16)),
+    shift(Book::Numbers, 29, (40, 40), (30, 1)),
+    shift(Book::Numbers, 30, (1, 16), (30, 2)),
+    shift(Book::Deuteronomy, 12, (32, 32), (13, 1)),
+    shift(Book::Deuteronomy, 13, (1, 18), (13, 2)),
+    shift(Book::Deuteronomy, 22, (30, 30), (23, 1)),
+    shift(Book::Deuteronomy, 23, (1, 25), (23, 2)),
+    shift(Book::Deuteronomy, 29, (1, 1), (28, 69)),
+    shift(Book::Deuteronomy, 29, (2, 29), (29, 1)),
+    shift(Book::FirstSamuel, 21, (1, 15), (21, 2)),
+    shift(Book::FirstSamuel, 23, (29, 29), (24, 1)),
+    shift(Book::FirstSamuel, 24, (1, 22), (24, 2)),
+    shift(Book::SecondSamuel, 18, (33, 33), (19, 1)),
+    shift(Book::SecondSamuel, 19, (1, 43), (19, 2)),
+    shift(Book::FirstKings, 4, (21, 34), (5, 1)),
+    shift(Book::FirstKings, 5, (1, 18), (5, 15)),
+    shift(Book::FirstKings, 22, (44, 53), (22, 45)),
+    shift(Book::SecondKings, 11, (21, 21), (12, 1)),
+    shift(Book::SecondKings, 12, (1, 21), (12, 2)),
+    shift(Book::FirstChronicles, 6, (1, 15), (5, 27)),
+    shift(Book::FirstChronicles, 6, (16, 81), (6, 1)),
+    shift(Book::SecondChronicles, 2, (1, 1), (1, 18)),
+    shift(Book::SecondChronicles, 2, (2, 18), (2, 1)),
+    shift(Book::SecondChronicles, 14, (1, 1), (13, 23)),
+    shift(Book::SecondChronicles, 14, (2, 15), (14, 1)),
+    shift(Book::Nehemiah, 4, (1, 6), (3, 33)),
+    shift(Book::Nehemiah, 4, (7, 23), (4, 1)),
+    shift(Book::Nehemiah, 9, (38, 38), (10, 1)),
+    shift(Book::Nehemiah, 10, (1, 39), (10, 2)),
+    shift(Book::Job, 41, (1, 8), (40, 25)),
+    shift(Book::Job, 41, (9, 34), (41, 1)),
+    shift(Book::Ecclesiastes, 5, (1, 1), (4, 17)),
+    shift(Book::Ecclesiastes, 5, (2, 20), (5, 1)),
+    shift(Book::SongOfSongs, 6, (13, 13), (7, 1)),
+    shift(Book::SongOfSongs, 7, (1, 13), (7, 2)),
+    shift(Book::Isaiah, 9, (1, 1), (8, 23)),
+    shift(Book::Isaiah, 9, (2, 21), (9, 1)),
+    shift(Book::Isaiah, 63, (19, 19), (63, 19)),
+    shift(Book::Isaiah, 64, (1, 1), (63, 19)),
+    shift(Book::Isaiah, 64, (2, 12), (64, 1)),
+    shift(Book::Jeremiah, 9, (1, 1), (8, 23)),
+    shift(Book::Jeremiah, 9, (2, 26), (9, 1)),
+    shift(Book::Ezekiel, 20, (45, 49), (21, 1)),
+    shift(Book::Ezekiel, 21, (1, 32), (21, 6)),
+    shift(Book::Daniel, 4, (1, 3), (3, 31)),
+    shift(Book::Daniel, 4, (4, 37), (4, 1)),
+    shift(Book::Daniel, 5, (31, 31), (6, 1)),
+    shift(Book::Daniel, 6, (1, 28), (6, 2)),
+    shift(Book::Hosea, 1, (10, 11), (2, 1)),
+    shift(Book::Hosea, 2, (1, 23), (2, 3)),
+    shift(Book::Hosea, 11, (12, 12), (12, 1)),
+    shift(Book::Hosea, 12, (1, 14), (12, 2)),
+    shift(Book::Hosea, 13, (16, 16), (14, 1)),
+    shift(Book::Hosea, 14, (1, 9), (14, 2)),
+    shift(Book::Joel, 2, (28, 32), (3, 1)),
+    shift(Book::Joel, 3, (1, 21), (4, 1)),
+    shift(Book::Jonah, 1, (17, 17), (2, 1)),
+    shift(Book::Jonah, 2, (1, 10), (2, 2)),
+    shift(Book::Micah, 5, (1, 1), (4, 14)),
+    shift(Book::Micah, 5, (2, 15), (5, 1)),
+    shift(Book::Nahum, 1, (15, 15), (2, 1)),
+    shift(Book::Nahum, 2, (1, 13), (2, 2)),
+    shift(Book::Zechariah, 1, (18, 21), (2, 1)),
+    shift(Book::Zechariah, 2, (1, 13), (2, 5)),
+    shift(Book::Malachi, 4, (1, 6), (3, 19)),
+];
+
+/// Psalm titles counted as verses in the Hebrew text, which the Septuagint and Vulgate follow.
+const PSALM_TITLES: &[VerseMapping] = &[
+    titled_psalms((3, 9), 1),
+    titled_psalms((12, 12), 1),
+    // The last two English verses of Psalm 13 are a single Hebrew verse.
+    shift(Book::Psalms, 13, (1, 5), (13, 2)),
+    shift(Book::Psalms, 13, (6, 6), (13, 6)),
+    titled_psalms((18, 22), 1),
+    titled_psalms((30, 31), 1),
+    titled_psalms((34, 34), 1),
+    titled_psalms((36, 36), 1),
+    titled_psalms((38, 42), 1),
+    titled_psalms((44, 49), 1),
+    titled_psalms((51, 52), 2),
+    titled_psalms((53, 53), 1),
+    titled_psalms((54, 54), 2),
+    titled_psalms((55, 59), 1),
+    titled_psalms((60, 60), 2),
+    titled_psalms((61, 65), 1),
+    titled_psalms((67, 70), 1),
+    titled_psalms((75, 77), 1),
+    titled_psalms((80, 81), 1),
+    titled_psalms((83, 85), 1),
+    titled_psalms((88, 89), 1),
+    titled_psalms((92, 92), 1),
+    titled_psalms((102, 102), 1),
+    titled_psalms((108, 108), 1),
+    titled_psalms((140, 140), 1),
+    titled_psalms((142, 142), 1),
+];
+
+/// The Greek numbering of the Psalms, applied on top of the Hebrew verse numbers.
+const GREEK_PSALMS: &[VerseMapping] = &[
+    shift(Book::Psalms, 10, (1, 18), (9, 22)),
+    shift_chapters(Book::Psalms, (11, 113), (10, 1)),
+    shift(Book::Psalms, 114, (1, 8), (113, 1)),
+    shift(Book::Psalms, 115, (1, 18), (113, 9)),
+    shift(Book::Psalms, 116, (1, 9), (114, 1)),
+    shift(Book::Psalms, 116, (10, 19), (115, 1)),
+    shift_chapters(Book::Psalms, (117, 146), (116, 1)),
+    shift(Book::Psalms, 147, (1, 11), (146, 1)),
+    shift(Book::Psalms, 147, (12, 20), (147, 1)),
+];
+
+impl VerseMapping {
+    fn forward(&self, book: Book, chapter: u8, verse: u8) -> Option<(u8, u8)> {
+        let (first_chapter, last_chapter) = self.chapters;
+        let (first_verse, last_verse) = self.verses;
+        if book != self.book
+            || !(first_chapter..=last_chapter).contains(&chapter)
+            || !(first_verse..=last_verse).contains(&verse)
+        {
+            return None;
+        }
+        Some((
+            self.to.0 + (chapter - first_chapter),
+            self.to.1 + (verse - first_verse),
+        ))
+    }
+
+    fn backward(&self, book: Book, chapter: u8, verse: u8) -> Option<(u8, u8)> {
+        let (first_chapter, last_chapter) = self.chapters;
+        let (first_verse, last_verse) = self.verses;
+        let (to_chapter, to_verse) = self.to;
+        let chapters = to_chapter..=to_chapter + (last_chapter - first_chapter);
+        let verses = to_verse..=to_verse.saturating_add(last_verse - first_verse);
+        if book != self.book || !chapters.contains(&chapter) || !verses.contains(&verse) {
+            return None;
+        }
+        Some((
+            first_chapter + (chapter - to_chapter),
+            first_verse + (verse - to_verse),
+        ))
+    }
+}
+
+impl Versification {
+    /// The mappings from the English versification, applied in order.
+    fn layers(&self) -> &'static [&'static [VerseMapping]] {
+        match self {
+            Versification::English => &[],
+            Versification::Masoretic => &[HEBREW_CHAPTERS, PSALM_TITLES],
+            // TODO: the Septuagint also orders some passages differently (e.g., Jeremiah 25-51
+            // and Exodus 35-40), which a chapter and verse mapping cannot express.
+            Versification::Septuagint => &[HEBREW_CHAPTERS, PSALM_TITLES, GREEK_PSALMS],
+            Versification::Vulgate => &[PSALM_TITLES, GREEK_PSALMS],
+        }
+    }
+
+    fn map_from_english(&self, book: Book, chapter: u8, verse: u8) -> (u8, u8) {
+        self.layers()
+            .iter()
+            .fold((chapter, verse), |(chapter, verse), mappings| {
+                mappings
+                    .iter()
+                    .find_map(|m| m.forward(book, chapter, verse))
+                    .unwrap_or((chapter, verse))
+            })
+    }
+
+    fn map_to_english(&self, book: Book, chapter: u8, verse: u8) -> (u8, u8) {
+        self.layers()
+            .iter()
+            .rev()
+            .fold((chapter, verse), |(chapter, verse), mappings| {
+                mappings
+                    .iter()
+                    .find_map(|m| m.backward(book, chapter, verse))
+                    .unwrap_or((chapter, verse))
+            })
+    }
+
+    /// Finds the English verse equivalent to a verse numbered in this versification.
+    ///
+    /// Fails if the verse does not exist in this versification, or has no English equivalent
+    /// (e.g., a Hebrew psalm title).
+    pub fn english_equivalent(
+        &self,
+        book: Book,
+        chapter: ChapterNumber,
+        verse: VerseNumber,
+    ) -> Result<Verse, String> {
+        let (english_chapter, english_verse) =
+            self.map_to_english(book, chapter.get(), verse.get());
+        let english = Verse::new(
+            book,
+            ChapterNumber::new(english_chapter)?,
+            VerseNumber::new(english_verse)?,
+        );
+        match english {
+            Ok(english)
+                if self.map_from_english(book, english_chapter, english_verse)
+                    == (chapter.get(), verse.get()) =>
+            {
+                Ok(english)
+            }
+            Err(e) if *self == Versification::English => Err(e),
+            _ => Err(format!(
+                "{book} {chapter}:{verse} does not exist in the {self} versification",
+            )),
+        }
+    }
+
+    /// Counts the verses of a chapter numbered in this versification, e.g. 24 for Malachi 3 in
+    /// the Hebrew text, where English Bibles have 18.
+    pub fn max_verse_count(&self, book: Book, chapter: ChapterNumber) -> Result<u8, String> {
+        (1..=ALL_VERSES.1)
+            .rev()
+            .find(|&verse| {
+                VerseNumber::new(verse)
+                    .and_then(|verse| self.english_equivalent(book, chapter, verse))
+                    .is_ok()
+            })
+            .ok_or_else(|| format!("{book} {chapter} does not exist in the {self} versification"))
+    }
+
+    /// Numbers the given verse in this versification.
+    ///
+    /// The returned verse is not validated against the verse tables of `Book`, which follow the
+    /// English versification.
+    pub fn number(&self, english: &Verse) -> Result<Verse, String> {
+        let book = english.book();
+        let (chapter, verse) =
+            self.map_from_english(book, english.chapter.number.get(), english.number.get());
+        Ok(Verse {
+            book,
+            chapter: Chapter {
+                book,
+                number: ChapterNumber::new(chapter)?,
+            },
+            number: VerseNumber::new(verse)?,
+        })
+    }
+}
+
+impl std::fmt::Display for Versification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Versification::English => write!(f, "English"),
+            Versification::Masoretic => write!(f, "Masoretic"),
+            Versification::Septuagint => write!(f, "Septuagint"),
+            Versification::Vulgate => write!(f, "Vulgate"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(
+        from: Versification,
+        book: Book,
+        chapter: u8,
+        verse: u8,
+        to: Versification,
+    ) -> Result<(u8, u8), String> {
+        let english =
+            from.english_equivalent(book, ChapterNumber::new(chapter)?, VerseNumber::new(verse)?)?;
+        let converted = to.number(&english)?;
+        Ok((converted.chapter.number.get(), converted.number.get()))
+    }
+
+    #[test]
+    fn converts_malachi_between_english_and_hebrew() {
+        use Versification::*;
+        assert_eq!(
+            convert(English, Book::Malachi, 4, 1, Masoretic),
+            Ok((3, 19))
+        );
+        assert_eq!(
+            convert(English, Book::Malachi, 4, 6, Masoretic),
+            Ok((3, 24))
+        );
+        assert_eq!(
+            convert(Masoretic, Book::Malachi, 3, 19, English),
+            Ok((4, 1))
+        );
+        assert_eq!(
+            convert(English, Book::Malachi, 3, 18, Masoretic),
+            Ok((3, 18))
+        );
+        assert!(convert(Masoretic, Book::Malachi, 4, 1, English).is_err());
+    }
+
+    #[test]
+    fn counts_the_verses_of_chapters_in_each_versification() {
+        use Versification::*;
+        let count = |versification: Versification, book, chapter| {
+            versification.max_verse_count(book, ChapterNumber::new(chapter).unwrap())
+        };
+        assert_eq!(count(English, Book::Malachi, 3), Ok(18));
+        assert_eq!(count(Masoretic, Book::Malachi, 3), Ok(24));
+        assert!(count(Masoretic, Book::Malachi, 4).is_err());
+        assert_eq!(count(Septuagint, Book::Psalms, 22), Ok(6));
+    }
+
+    #[test]
+    fn converts_psalms_between_english_and_greek() {
+        use Versification::*;
+        assert_eq!(
+            convert(English, Book::Psalms, 23, 1, Septuagint),
+            Ok((22, 1))
+        );
+        assert_eq!(
+            convert(Septuagint, Book::Psalms, 22, 6, English),
+            Ok((23, 6))
+        );
+        assert_eq!(convert(English, Book::Psalms, 10, 1, Vulgate), Ok((9, 22)));
+        assert_eq!(
+            convert(English, Book::Psalms, 116, 10, Vulgate),
+            Ok((115, 1))
+        );
+        assert_eq!(
+            convert(Vulgate, Book::Psalms, 113, 9, English),
+            Ok((115, 1))
+        );
+        assert_eq!(
+            convert(English, Book::Psalms, 150, 6, Septuagint),
+            Ok((150, 6))
+        );
+    }
+
+    #[test]
+    fn counts_psalm_titles_as_verses() {
+        use Versification::*;
+        assert_eq!(
+            convert(English, Book::Psalms, 51, 1, Masoretic),
+            Ok((51, 3))
+        );
+        assert_eq!(
+            convert(English, Book::Psalms, 51, 1, Septuagint),
+            Ok((50, 3))
+        );
+        assert_eq!(convert(Masoretic, Book::Psalms, 3, 9, English), Ok((3, 8)));
+        assert!(convert(Masoretic, Book::Psalms, 3, 1, English).is_err());
+        assert_eq!(
+            convert(Masoretic, Book::Psalms, 13, 6, English),
+            Ok((13, 5))
+        );
+    }
+
+    #[test]
+    fn converts_between_non_english_versifications() {
+        use Versification::*;
+        assert_eq!(
+            convert(Masoretic, Book::Psalms, 51, 3, Vulgate),
+            Ok((50, 3))
+        );
+        assert_eq!(convert(Masoretic, Book::Joel, 4, 1, Vulgate), Ok((3, 1)));
+        assert_eq!(convert(English, Book::Genesis, 1, 1, Vulgate), Ok((1, 1)));
+    }
+
+    #[test]
+    fn round_trips_every_english_verse() {
+        for versification in [
+            Versification::Masoretic,
+            Versification::Septuagint,
+            Versification::Vulgate,
+        ] {
+            for book in Book::bible() {
+                for chapter in 1..=book.chapter_count() {
+                    for verse in 1..=book.max_verses_in_chapter(chapter).unwrap() {
+                        let converted =
+                            convert(Versification::English, *book, chapter, verse, versification)
+                                .unwrap();
+                        let back = convert(
+                            versification,
+                            *book,
+                            converted.0,
+                            converted.1,
+                            Versification::English,
+                        );
+                        // Merged verses convert back to the first English verse of the merge.
+                        if *book == Book::Psalms && (chapter, verse) == (13, 6) {
+                            assert_eq!(back, Ok((13, 5)));
+                        } else if versification != Versification::Vulgate
+                            && *book == Book::Isaiah
+                            && (chapter, verse) == (64, 1)
+                        {
+                            assert_eq!(back, Ok((63, 19)));
+                        } else {
+                            assert_eq!(back, Ok((chapter, verse)), "{book} {chapter}:{verse}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+}